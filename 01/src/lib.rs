@@ -0,0 +1,311 @@
+use std::{
+    cmp::Reverse,
+    error::Error,
+    fmt::{self, Display},
+    ops::Range,
+};
+
+use aho_corasick::AhoCorasick;
+use aoc_common::{Answer, Param, Params, ParseError, Rng, Solution, Source};
+
+pub mod stream;
+
+pub struct Day01;
+
+impl Solution for Day01 {
+    type Input = Vec<Line>;
+
+    const PARAMS: &'static [Param] = &[MISSING_DIGITS];
+
+    fn check_params(params: &Params) -> Result<(), String> {
+        Policy::from_param(params.get(&MISSING_DIGITS)).map(|_| ())
+    }
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse(input)
+    }
+
+    fn part1(input: &Self::Input, params: &Params) -> Result<Answer, String> {
+        part1(input, Policy::from_param(params.get(&MISSING_DIGITS))?).map_err(|e| e.to_string())
+    }
+
+    fn part2(input: &Self::Input, params: &Params) -> Result<Answer, String> {
+        part2(input, Policy::from_param(params.get(&MISSING_DIGITS))?).map_err(|e| e.to_string())
+    }
+
+    fn explain(input: &Self::Input, params: &Params, part: u8) -> Option<String> {
+        let vocabulary = if part == 1 { DIGITS } else { WORDS };
+        Some(explain(input, &CalibrationDecoder::new(vocabulary), Policy::from_param(params.get(&MISSING_DIGITS)).ok()?))
+    }
+
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        generate(rng, size)
+    }
+}
+
+const MISSING_DIGITS: Param = Param { name: "missing_digits", default: 0, help: "lines without a digit: 0 fails, 1 skips them, 2 counts them as 0, like `aoc calibrate --missing fail|skip|zero`" };
+
+/// The digits of part 1
+pub const DIGITS: &[(&str, u8)] = &[("0", 0), ("1", 1), ("2", 2), ("3", 3), ("4", 4), ("5", 5), ("6", 6), ("7", 7), ("8", 8), ("9", 9)];
+
+/// The digits and the spelled out ones of part 2, the puzzle has no `zero`
+pub const WORDS: &[(&str, u8)] = &[
+    ("1", 1), ("2", 2), ("3", 3), ("4", 4), ("5", 5), ("6", 6), ("7", 7), ("8", 8), ("9", 9),
+    ("one", 1), ("two", 2), ("three", 3), ("four", 4), ("five", 5), ("six", 6), ("seven", 7), ("eight", 8), ("nine", 9),
+];
+
+/// A line of the calibration document with its number, counting from 1
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Line {
+    pub number: usize,
+    pub text: String,
+}
+
+/// What to do with lines without any digit
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Policy {
+    Strict,
+    Skip,
+    Zero,
+}
+
+impl Policy {
+    /// The policy for a value of the `missing_digits` parameter
+    pub fn from_param(value: i64) -> Result<Policy, String> {
+        match value {
+            0 => Ok(Policy::Strict),
+            1 => Ok(Policy::Skip),
+            2 => Ok(Policy::Zero),
+            _ => Err(format!("missing_digits must be 0 (fail), 1 (skip) or 2 (zero), not {}", value)),
+        }
+    }
+}
+
+/// A line the decoder found no digit in
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NoDigit {
+    pub line: usize,
+    pub text: String,
+}
+
+impl Display for NoDigit {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        // Underlines the line like a `ParseError` points at the problem
+        writeln!(f, "line {}: no digit in `{}`", self.line, self.text)?;
+        let gutter = " ".repeat(self.line.to_string().len());
+        writeln!(f, "{} |", gutter)?;
+        writeln!(f, "{} | {}", self.line, self.text)?;
+        write!(f, "{} | {}", gutter, "^".repeat(self.text.chars().count().max(1)))
+    }
+}
+
+impl Error for NoDigit {}
+
+/// A token of the vocabulary found in a line
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Token {
+    pub value: u8,
+    /// Where it is in the line, in bytes
+    pub span: Range<usize>,
+}
+
+/// Finds the first and the last digit of a line, for any tokens standing for digits.
+/// Tokens may overlap, in `twone` the first digit is 2 and the last one 1.
+pub struct CalibrationDecoder {
+    automaton: AhoCorasick,
+    values: Vec<u8>,
+    /// The value of each digit byte, when all tokens are single digits and the
+    /// automaton isn't needed
+    digits: Option<[Option<u8>; 10]>,
+}
+
+impl CalibrationDecoder {
+    /// A decoder for the tokens of `vocabulary` with their values, which must not be empty
+    pub fn new(vocabulary: &[(&str, u8)]) -> Self {
+        assert!(vocabulary.iter().all(|(token, _)| !token.is_empty()), "tokens must not be empty");
+        let automaton = AhoCorasick::new(vocabulary.iter().map(|(token, _)| token)).expect("the vocabulary is too big");
+        let single_digits = vocabulary.iter().all(|(token, _)| matches!(token.as_bytes(), [b'0'..=b'9']));
+        let digits = single_digits.then(|| {
+            let mut digits = [None; 10];
+            for &(token, value) in vocabulary {
+                digits[(token.as_bytes()[0] - b'0') as usize] = Some(value);
+            }
+            digits
+        });
+        CalibrationDecoder { automaton, values: vocabulary.iter().map(|&(_, value)| value).collect(), digits }
+    }
+
+    /// A decoder for a vocabulary with a token and its digit on each line, e.g. `eins 1`
+    pub fn from_table(table: &str) -> Result<Self, ParseError> {
+        let source = Source::new(table);
+        let mut vocabulary = Vec::new();
+        for line in table.lines().filter(|l| !l.trim().is_empty()) {
+            let (token, value) = source.split_once(line.trim(), " ")?;
+            let value = value.trim();
+            if value.len() != 1 || !value.as_bytes()[0].is_ascii_digit() {
+                return Err(source.error(value, "a digit"));
+            }
+            vocabulary.push((token, value.as_bytes()[0] - b'0'));
+        }
+        Ok(CalibrationDecoder::new(&vocabulary))
+    }
+
+    /// The first and the last token in `line`, the same one if there is only one
+    pub fn decode(&self, line: impl AsRef<[u8]>) -> Option<(Token, Token)> {
+        let line = line.as_ref();
+        let mut tokens = self.automaton.find_overlapping_iter(line).map(|m| Token { value: self.values[m.pattern()], span: m.range() });
+        let first = tokens.next()?;
+        // Of tokens starting at the same byte the longest one counts
+        let (mut first, mut last) = (first.clone(), first);
+        for token in tokens {
+            if (token.span.start, Reverse(token.span.end)) < (first.span.start, Reverse(first.span.end)) {
+                first = token.clone();
+            }
+            if (token.span.start, token.span.end) > (last.span.start, last.span.end) {
+                last = token;
+            }
+        }
+        Some((first, last))
+    }
+
+    pub fn first_last(&self, line: impl AsRef<[u8]>) -> Option<(u8, u8)> {
+        match &self.digits {
+            Some(digits) => stream::first_last_digit(line.as_ref(), digits),
+            None => self.decode(line).map(|(first, last)| (first.value, last.value)),
+        }
+    }
+}
+
+pub fn parse(input: &str) -> Result<Vec<Line>, ParseError> {
+    Ok(input
+        .lines()
+        .enumerate()
+        .filter(|(_, l)| !l.is_empty())
+        .map(|(i, l)| Line { number: i + 1, text: l.to_string() })
+        .collect())
+}
+
+/// The sum of the calibration values, the first digit of each line followed by its last one
+pub fn calibrate(decoder: &CalibrationDecoder, lines: &[Line], policy: Policy) -> Result<u64, NoDigit> {
+    let mut sum = 0;
+    for line in lines {
+        match decoder.first_last(&line.text) {
+            Some((first, last)) => sum += first as u64 * 10 + last as u64,
+            None if policy == Policy::Strict => return Err(NoDigit { line: line.number, text: line.text.clone() }),
+            None => {}
+        }
+    }
+    Ok(sum)
+}
+
+// The digits each line comes down to and the tokens they come from
+fn explain(lines: &[Line], decoder: &CalibrationDecoder, policy: Policy) -> String {
+    let mut out = String::new();
+    for line in lines {
+        let text = &line.text;
+        out += &match decoder.decode(text) {
+            Some((first, last)) => format!(
+                "line {}: {} from `{}` at {}..{}, {} from `{}` at {}..{}: {}\n",
+                line.number,
+                first.value,
+                &text[first.span.clone()],
+                first.span.start,
+                first.span.end,
+                last.value,
+                &text[last.span.clone()],
+                last.span.start,
+                last.span.end,
+                first.value as u64 * 10 + last.value as u64
+            ),
+            None => {
+                let outcome = match policy {
+                    Policy::Strict => "fails",
+                    Policy::Skip => "skipped",
+                    Policy::Zero => "0",
+                };
+                format!("line {}: no digit in `{}`: {}\n", line.number, text, outcome)
+            }
+        };
+    }
+    out
+}
+
+fn solve(lines: &[Line], vocabulary: &[(&str, u8)], policy: Policy) -> Result<Answer, NoDigit> {
+    calibrate(&CalibrationDecoder::new(vocabulary), lines, policy).map(Answer::from)
+}
+
+pub fn part1(input: &[Line], policy: Policy) -> Result<Answer, NoDigit> {
+    solve(input, DIGITS, policy)
+}
+
+pub fn part2(input: &[Line], policy: Policy) -> Result<Answer, NoDigit> {
+    solve(input, WORDS, policy)
+}
+
+/// `size` lines of letters, digits and spelled out digits (some of them sharing letters like
+/// `twone`), each with at least one digit
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    const WORDS: [&str; 12] = ["one", "two", "three", "four", "five", "six", "seven", "eight", "nine", "twone", "eightwo", "oneight"];
+
+    let mut out = String::new();
+    for _ in 0..size {
+        let mut line = String::new();
+        for _ in 0..rng.range(1..=6) {
+            match rng.below(3) {
+                0 => line += *rng.pick(&WORDS),
+                _ => (0..rng.range(1..=4)).for_each(|_| line.push((b'a' + rng.below(26) as u8) as char)),
+            }
+        }
+        for _ in 0..rng.range(1..=3) {
+            let at = rng.below(line.len() + 1);
+            line.insert(at, (b'1' + rng.below(9) as u8) as char);
+        }
+        out += &line;
+        out.push('\n');
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn lines(text: &str) -> Vec<Line> {
+        parse(text).unwrap()
+    }
+
+    #[test]
+    fn overlapping_tokens() {
+        let decoder = CalibrationDecoder::new(WORDS);
+        assert_eq!(decoder.first_last("xtwone3four"), Some((2, 4)));
+        assert_eq!(decoder.first_last("eightwo"), Some((8, 2)));
+        assert_eq!(decoder.first_last("7"), Some((7, 7)));
+        assert_eq!(decoder.first_last("abc"), None);
+        let (first, last) = decoder.decode("zoneight234").unwrap();
+        assert_eq!((first.span, last.span), (1..4, 10..11));
+    }
+
+    #[test]
+    fn other_vocabularies() {
+        let decoder = CalibrationDecoder::from_table("null 0\neins 1\nzwei 2\ndrei 3\n").unwrap();
+        assert_eq!(decoder.first_last("zweinsxnull"), Some((2, 0)));
+        assert!(CalibrationDecoder::from_table("eins x").is_err());
+        assert!(CalibrationDecoder::from_table("eins").is_err());
+    }
+
+    #[test]
+    fn lines_without_digits() {
+        let input = lines("1abc2\n\nnone\nx7\n");
+        let decoder = CalibrationDecoder::new(DIGITS);
+        assert_eq!(calibrate(&decoder, &input, Policy::Strict), Err(NoDigit { line: 3, text: "none".to_string() }));
+        assert_eq!(part1(&input, Policy::Strict).unwrap_err().to_string(), "line 3: no digit in `none`\n  |\n3 | none\n  | ^^^^");
+        assert!(Policy::from_param(7).is_err());
+        assert_eq!(calibrate(&decoder, &input, Policy::Skip), Ok(89));
+        assert_eq!(calibrate(&decoder, &input, Policy::Zero), Ok(89));
+        assert_eq!(calibrate(&CalibrationDecoder::new(WORDS), &input, Policy::Strict), Ok(89 + 11));
+        assert_eq!(
+            explain(&input[..2], &decoder, Policy::Skip),
+            "line 1: 1 from `1` at 0..1, 2 from `2` at 4..5: 12\nline 3: no digit in `none`: skipped\n"
+        );
+    }
+}
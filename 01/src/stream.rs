@@ -0,0 +1,366 @@
+use std::{
+    error::Error,
+    fmt::{self, Display},
+    io::{self, Read},
+    mem,
+    ops::RangeInclusive,
+};
+
+use rayon::prelude::*;
+
+use crate::{CalibrationDecoder, NoDigit, Policy};
+
+/// How much of the input a thread decodes at a time, chunks end at a newline
+const CHUNK: usize = 1 << 20;
+
+/// Bytes looked at at once by the digit and newline scans
+const LANES: usize = 64;
+
+/// What summing up the calibration values of a document came to
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Totals {
+    pub sum: u64,
+    /// Lines with a calibration value, counting lines without a digit that count as 0
+    pub lines: u64,
+    /// Lines without a digit that were skipped or counted as 0
+    pub missing: u64,
+    pub bytes: u64,
+}
+
+#[derive(Debug)]
+pub enum StreamError {
+    Io(io::Error),
+    NoDigit(NoDigit),
+}
+
+impl Display for StreamError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            StreamError::Io(e) => write!(f, "could not read the input: {}", e),
+            StreamError::NoDigit(e) => write!(f, "{}", e),
+        }
+    }
+}
+
+impl Error for StreamError {}
+
+impl From<io::Error> for StreamError {
+    fn from(e: io::Error) -> Self {
+        StreamError::Io(e)
+    }
+}
+
+impl From<NoDigit> for StreamError {
+    fn from(e: NoDigit) -> Self {
+        StreamError::NoDigit(e)
+    }
+}
+
+// Bit i is set for the bytes of `block` (at most LANES long) in `range`, which must be
+// ASCII. Eight bytes are compared at a time in a u64: adding to the low 7 bits of each
+// byte carries into its high bit exactly when it is past a bound, without carrying into
+// the next byte. This is several times faster than a loop over the bytes, which the
+// compiler only vectorizes well for newer CPUs than it builds for by default.
+#[inline(always)]
+fn mask(block: &[u8], range: RangeInclusive<u8>) -> u64 {
+    const ONES: u64 = u64::MAX / 255;
+    const HIGH: u64 = ONES << 7;
+    let (low, high) = (*range.start() as u64, *range.end() as u64);
+    let words = block.chunks_exact(8);
+    let remainder = words.remainder();
+    let mut mask = 0;
+    for (i, word) in words.enumerate() {
+        let word = u64::from_le_bytes(word.try_into().unwrap());
+        let low_bits = word & !HIGH;
+        let at_least = low_bits + ONES * (128 - low);
+        let at_most = !(low_bits + ONES * (127 - high));
+        let found = at_least & at_most & !word & HIGH;
+        // Gathers the high bits of the bytes into the top byte
+        mask |= ((found >> 7).wrapping_mul(0x0102040810204080) >> 56) << (i * 8);
+    }
+    for (i, b) in remainder.iter().enumerate() {
+        mask |= (range.contains(b) as u64) << (block.len() - remainder.len() + i);
+    }
+    mask
+}
+
+// The masks of `bytes` block by block with the offset of the block, whole blocks are
+// passed as arrays so their length is known when compiling
+fn masks(bytes: &[u8], range: RangeInclusive<u8>) -> impl Iterator<Item = (usize, u64)> + '_ {
+    let blocks = bytes.chunks_exact(LANES);
+    let remainder = blocks.remainder();
+    let whole = range.clone();
+    blocks
+        .map(move |block| mask(<&[u8; LANES]>::try_from(block).unwrap(), whole.clone()))
+        .chain(std::iter::once(mask(remainder, range)))
+        .enumerate()
+        .map(|(i, mask)| (i * LANES, mask))
+}
+
+const DIGIT: RangeInclusive<u8> = b'0'..=b'9';
+const NEWLINE: RangeInclusive<u8> = b'\n'..=b'\n';
+
+/// The values of the first and the last digit in `line`, looking at LANES bytes at a
+/// time from both ends. Digits without a value in `digits` don't count.
+pub fn first_last_digit(line: &[u8], digits: &[Option<u8>; 10]) -> Option<(u8, u8)> {
+    let value = |at: usize| digits[(line[at] - b'0') as usize].map(|v| (at, v));
+    let bits = |offset: usize, mut mask: u64| std::iter::from_fn(move || {
+        let bit = mask.trailing_zeros() as usize;
+        mask &= mask.wrapping_sub(1);
+        (bit < 64).then_some(offset + bit)
+    });
+    let (start, first) = masks(line, DIGIT).flat_map(|(offset, mask)| bits(offset, mask)).find_map(value)?;
+
+    // Blocks from the end, the last one might be shorter
+    let mut end = line.len();
+    while end > start {
+        let block_start = end.saturating_sub(LANES).max(start);
+        let mut found = mask(&line[block_start..end], DIGIT);
+        while found != 0 {
+            let at = block_start + (63 - found.leading_zeros() as usize);
+            if let Some((_, v)) = value(at) {
+                return Some((first, v));
+            }
+            found &= !(1 << (at - block_start));
+        }
+        end = block_start;
+    }
+    Some((first, first))
+}
+
+// Calls `f` with each line of `bytes`, the number of newlines before it and its first and
+// last digit. Returns the number of newlines, or stops at the first line `f` returns
+// false for.
+fn for_each_line(decoder: &CalibrationDecoder, bytes: &[u8], mut f: impl FnMut(u64, &[u8], Option<(u8, u8)>) -> bool) -> u64 {
+    let mut start = 0;
+    let mut newlines = 0;
+    match &decoder.digits {
+        // Digits and newlines in one go: the first and the last digit of a line are the
+        // lowest and the highest bits of the digits before its newline
+        Some(digits) => {
+            let value = |offset: usize, bit: u32| digits[(bytes[offset + bit as usize] - b'0') as usize];
+            let (mut first, mut last) = (None, 0);
+            let newline_masks = masks(bytes, NEWLINE);
+            for ((offset, mut newline_mask), (_, mut digit_mask)) in newline_masks.zip(masks(bytes, DIGIT)) {
+                loop {
+                    let line_end = newline_mask.trailing_zeros();
+                    let before = if line_end == 64 { u64::MAX } else { (1 << line_end) - 1 };
+                    let mut lower = digit_mask & before;
+                    while first.is_none() && lower != 0 {
+                        first = value(offset, lower.trailing_zeros());
+                        lower &= lower - 1;
+                    }
+                    let mut upper = digit_mask & before;
+                    while upper != 0 {
+                        let bit = 63 - upper.leading_zeros();
+                        if let Some(v) = value(offset, bit) {
+                            last = v;
+                            break;
+                        }
+                        upper &= !(1 << bit);
+                    }
+                    digit_mask &= !before;
+                    if line_end == 64 {
+                        break;
+                    }
+                    let at = offset + line_end as usize;
+                    if !f(newlines, &bytes[start..at], first.take().map(|first| (first, last))) {
+                        return newlines;
+                    }
+                    newlines += 1;
+                    start = at + 1;
+                    newline_mask &= newline_mask - 1;
+                }
+            }
+            if start < bytes.len() {
+                f(newlines, &bytes[start..], first.map(|first| (first, last)));
+            }
+        }
+        None => {
+            for (offset, mut found) in masks(bytes, NEWLINE) {
+                while found != 0 {
+                    let end = offset + found.trailing_zeros() as usize;
+                    if !f(newlines, &bytes[start..end], decoder.first_last(&bytes[start..end])) {
+                        return newlines;
+                    }
+                    newlines += 1;
+                    start = end + 1;
+                    found &= found - 1;
+                }
+            }
+            if start < bytes.len() {
+                f(newlines, &bytes[start..], decoder.first_last(&bytes[start..]));
+            }
+        }
+    }
+    newlines
+}
+
+// What a chunk came to, with its first line without a digit when that fails. The line is
+// numbered from the start of the chunk.
+struct Chunk {
+    totals: Totals,
+    newlines: u64,
+    missing: Option<(u64, String)>,
+}
+
+fn scan(decoder: &CalibrationDecoder, bytes: &[u8], policy: Policy) -> Chunk {
+    let mut totals = Totals { bytes: bytes.len() as u64, ..Totals::default() };
+    let mut missing = None;
+    let newlines = for_each_line(decoder, bytes, |line_index, line, found| {
+        let line = line.strip_suffix(b"\r").unwrap_or(line);
+        match found {
+            _ if line.is_empty() => {}
+            Some((first, last)) => {
+                totals.sum += first as u64 * 10 + last as u64;
+                totals.lines += 1;
+            }
+            None if policy == Policy::Strict => {
+                missing = Some((line_index, String::from_utf8_lossy(line).into_owned()));
+                return false;
+            }
+            None => {
+                totals.missing += 1;
+                if policy == Policy::Zero {
+                    totals.lines += 1;
+                }
+            }
+        }
+        true
+    });
+    Chunk { totals, newlines, missing }
+}
+
+// Adds the chunks in the order they were read
+fn add(totals: &mut Totals, newlines: &mut u64, chunks: Vec<Chunk>) -> Result<(), NoDigit> {
+    for chunk in chunks {
+        if let Some((line, text)) = chunk.missing {
+            return Err(NoDigit { line: (*newlines + line + 1) as usize, text });
+        }
+        totals.sum += chunk.totals.sum;
+        totals.lines += chunk.totals.lines;
+        totals.missing += chunk.totals.missing;
+        totals.bytes += chunk.totals.bytes;
+        *newlines += chunk.newlines;
+    }
+    Ok(())
+}
+
+/// Sums up the calibration values of a document in memory, e.g. a memory mapped file.
+/// It is cut into chunks at newlines that are decoded in parallel.
+pub fn calibrate_bytes(decoder: &CalibrationDecoder, bytes: &[u8], policy: Policy) -> Result<Totals, NoDigit> {
+    let mut chunks = Vec::new();
+    let mut start = 0;
+    while start < bytes.len() {
+        let end = match bytes[(start + CHUNK).min(bytes.len())..].iter().position(|&b| b == b'\n') {
+            Some(newline) => start + CHUNK + newline + 1,
+            None => bytes.len(),
+        };
+        chunks.push(&bytes[start..end]);
+        start = end;
+    }
+
+    let mut totals = Totals::default();
+    add(&mut totals, &mut 0, chunks.par_iter().map(|chunk| scan(decoder, chunk, policy)).collect())?;
+    Ok(totals)
+}
+
+// Reads the next chunk into `buffer`: what was left over from the last one in `rest`,
+// then about CHUNK bytes up to a newline. The bytes after that newline go to `rest`. A
+// line longer than CHUNK is read completely. Returns whether the input ended.
+fn fill(reader: &mut impl Read, buffer: &mut Vec<u8>, rest: &mut Vec<u8>) -> io::Result<bool> {
+    buffer.clear();
+    buffer.append(rest);
+    let mut searched = 0;
+    loop {
+        // Doubles the buffer for long lines
+        let want = CHUNK.saturating_sub(buffer.len()).max(buffer.len()) as u64;
+        let read = reader.by_ref().take(want).read_to_end(buffer)? as u64;
+        if read < want {
+            return Ok(true);
+        }
+        if let Some(newline) = buffer[searched..].iter().rposition(|&b| b == b'\n') {
+            rest.extend_from_slice(&buffer[searched + newline + 1..]);
+            buffer.truncate(searched + newline + 1);
+            return Ok(false);
+        }
+        searched = buffer.len();
+    }
+}
+
+// Fills as many of `buffers` as there is input for, returns how many and whether the
+// input ended
+fn fill_all(reader: &mut impl Read, buffers: &mut [Vec<u8>], rest: &mut Vec<u8>) -> io::Result<(usize, bool)> {
+    for (i, buffer) in buffers.iter_mut().enumerate() {
+        if fill(reader, buffer, rest)? {
+            return Ok((i + 1, true));
+        }
+    }
+    Ok((buffers.len(), false))
+}
+
+/// Sums up the calibration values of a document read piece by piece. Each thread
+/// decodes a chunk while the next ones are read, the memory used stays at a few chunks
+/// per thread whatever the size of the document.
+pub fn calibrate_reader(decoder: &CalibrationDecoder, mut reader: impl Read + Send, policy: Policy) -> Result<Totals, StreamError> {
+    let batch = rayon::current_num_threads() * 2;
+    let mut reading = vec![Vec::with_capacity(CHUNK); batch];
+    let mut scanning = vec![Vec::with_capacity(CHUNK); batch];
+    let mut rest = Vec::new();
+    let mut totals = Totals::default();
+    let mut newlines = 0;
+
+    let (mut filled, mut ended) = fill_all(&mut reader, &mut scanning, &mut rest)?;
+    loop {
+        let scan_all = |buffers: &[Vec<u8>]| buffers.par_iter().map(|buffer| scan(decoder, buffer, policy)).collect::<Vec<_>>();
+        if ended {
+            add(&mut totals, &mut newlines, scan_all(&scanning[..filled]))?;
+            return Ok(totals);
+        }
+        let (read, chunks) = rayon::join(|| fill_all(&mut reader, &mut reading, &mut rest), || scan_all(&scanning[..filled]));
+        add(&mut totals, &mut newlines, chunks)?;
+        (filled, ended) = read?;
+        mem::swap(&mut reading, &mut scanning);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{DIGITS, WORDS};
+
+    // Lines of every length around LANES with digits near both ends, and longer than a chunk
+    fn document() -> String {
+        let mut out = String::new();
+        for i in 0..3 * LANES {
+            let filler = "x".repeat(i);
+            out += &format!("{}{}{}{}{}\n", filler, i % 10, filler, (i + 3) % 10, filler);
+        }
+        out += &"a1b".repeat(CHUNK / 2);
+        out += "\r\n\nseven\nx9\n";
+        out
+    }
+
+    #[test]
+    fn same_as_line_by_line() {
+        let text = document();
+        let lines = crate::parse(&text).unwrap();
+        for (vocabulary, policy) in [(DIGITS, Policy::Skip), (WORDS, Policy::Strict), (DIGITS, Policy::Zero)] {
+            let decoder = CalibrationDecoder::new(vocabulary);
+            let totals = calibrate_bytes(&decoder, text.as_bytes(), policy).unwrap();
+            assert_eq!(totals.sum, crate::calibrate(&decoder, &lines, policy).unwrap());
+            assert_eq!(totals.bytes, text.len() as u64);
+            assert_eq!(calibrate_reader(&decoder, text.as_bytes(), policy).unwrap(), totals);
+        }
+        let totals = calibrate_bytes(&CalibrationDecoder::new(DIGITS), text.as_bytes(), Policy::Zero).unwrap();
+        assert_eq!((totals.lines, totals.missing), (lines.len() as u64, 1));
+    }
+
+    #[test]
+    fn numbers_lines_across_chunks() {
+        let text = document();
+        let error = calibrate_reader(&CalibrationDecoder::new(DIGITS), text.as_bytes(), Policy::Strict).unwrap_err();
+        let StreamError::NoDigit(error) = error else { panic!("{}", error) };
+        assert_eq!(error, NoDigit { line: 3 * LANES + 3, text: "seven".to_string() });
+    }
+}
@@ -0,0 +1,197 @@
+use std::{collections::BTreeMap, ops::RangeInclusive};
+
+use crate::{Bag, Color, Colors, Game};
+
+/// How likely each number of cubes of a color is before seeing any round, the same for
+/// every color and independent between colors
+#[derive(Debug, Clone)]
+pub struct Prior {
+    /// Natural logarithms by number of cubes, -inf for impossible ones
+    log_weights: Vec<f64>,
+}
+
+impl Prior {
+    /// Every number from 0 to `max` alike
+    pub fn uniform(max: usize) -> Self {
+        Prior { log_weights: vec![0.0; max + 1] }
+    }
+
+    /// Poisson distributed around `mean`, which must be positive, cut off after `max`
+    pub fn poisson(mean: f64, max: usize) -> Self {
+        let log_factorials = log_factorials(max);
+        Prior { log_weights: (0..=max).map(|n| n as f64 * mean.ln() - mean - log_factorials[n]).collect() }
+    }
+
+    pub fn max(&self) -> usize {
+        self.log_weights.len() - 1
+    }
+}
+
+// ln(n!) for n up to `max`
+fn log_factorials(max: usize) -> Vec<f64> {
+    let mut table = vec![0.0; max + 1];
+    for n in 1..=max {
+        table[n] = table[n - 1] + (n as f64).ln();
+    }
+    table
+}
+
+/// The bags that could have been behind the rounds of a game, with how likely they are.
+/// Each round draws its cubes from the bag without replacement and puts them back
+/// afterwards, so a round that shows `k` cubes of each color out of `K` drawn has a
+/// likelihood of `Π binomial(n, k) / binomial(N, K)` for a bag with `n` cubes of each
+/// color and `N` in all. Everything is computed with logarithms to not overflow.
+///
+/// Every bag between the fewest cubes and the maximum of the prior is looked at, that
+/// is exponential in the number of colors.
+#[derive(Debug, Clone)]
+pub struct Posterior {
+    colors: Vec<Color>,
+    /// The counts of each bag in the order of `colors`
+    bags: Vec<Vec<usize>>,
+    log_likelihoods: Vec<f64>,
+    probabilities: Vec<f64>,
+}
+
+impl Posterior {
+    /// None when the prior rules out every bag the rounds could come from
+    pub fn new(colors: &Colors, game: &Game, prior: &Prior) -> Option<Self> {
+        let max = prior.max();
+        let fewest = game.fewest();
+        let low: Vec<usize> = colors.iter().map(|color| fewest.get(color)).collect();
+        if low.iter().any(|&n| n > max) {
+            return None;
+        }
+        let rounds: Vec<Vec<usize>> = game.rounds.iter().map(|round| colors.iter().map(|color| round.get(color)).collect()).collect();
+        let log_factorials = log_factorials(max * colors.len());
+        let log_binomial = |n: usize, k: usize| log_factorials[n] - log_factorials[k] - log_factorials[n - k];
+
+        let mut posterior = Posterior { colors: colors.iter().collect(), bags: Vec::new(), log_likelihoods: Vec::new(), probabilities: Vec::new() };
+        let mut bag = low.clone();
+        loop {
+            let total: usize = bag.iter().sum();
+            let log_likelihood: f64 = rounds
+                .iter()
+                .map(|shown| shown.iter().zip(&bag).map(|(&k, &n)| log_binomial(n, k)).sum::<f64>() - log_binomial(total, shown.iter().sum()))
+                .sum();
+            let log_prior: f64 = bag.iter().map(|&n| prior.log_weights[n]).sum();
+            posterior.bags.push(bag.clone());
+            posterior.log_likelihoods.push(log_likelihood);
+            posterior.probabilities.push(log_likelihood + log_prior);
+
+            // The next bag, counting up like an odometer
+            let Some(i) = (0..bag.len()).find(|&i| bag[i] < max) else { break };
+            bag[i] += 1;
+            bag[..i].copy_from_slice(&low[..i]);
+        }
+
+        // Normalized in log space first, the largest becomes 1
+        let most = posterior.probabilities.iter().copied().fold(f64::NEG_INFINITY, f64::max);
+        if most == f64::NEG_INFINITY {
+            return None;
+        }
+        posterior.probabilities.iter_mut().for_each(|p| *p = (*p - most).exp());
+        let sum: f64 = posterior.probabilities.iter().sum();
+        posterior.probabilities.iter_mut().for_each(|p| *p /= sum);
+        Some(posterior)
+    }
+
+    fn bag(&self, counts: &[usize]) -> Bag {
+        Bag { cubes: self.colors.iter().copied().zip(counts.iter().copied()).filter(|&(_, n)| n > 0).collect() }
+    }
+
+    /// The bag that makes the rounds the most likely, whatever the prior. Ties go to
+    /// the bag with the fewest cubes of the last color, then of the one before and so on.
+    pub fn most_likely(&self) -> Bag {
+        let (best, _) = self.bags.iter().zip(&self.log_likelihoods).fold((None, f64::NEG_INFINITY), |(best, most), (bag, &l)| if l > most { (Some(bag), l) } else { (best, most) });
+        self.bag(best.unwrap())
+    }
+
+    /// The probability of each number of cubes of `color`, by number
+    pub fn marginal(&self, color: Color) -> Vec<f64> {
+        let mut by_count: BTreeMap<usize, f64> = BTreeMap::new();
+        if let Some(i) = self.colors.iter().position(|&c| c == color) {
+            for (bag, &p) in self.bags.iter().zip(&self.probabilities) {
+                *by_count.entry(bag[i]).or_default() += p;
+            }
+        }
+        let len = by_count.keys().last().map_or(1, |&n| n + 1);
+        let mut marginal = vec![0.0; len];
+        for (n, p) in by_count {
+            marginal[n] = p;
+        }
+        // A color the game doesn't know has no cubes
+        if marginal.iter().all(|&p| p == 0.0) {
+            marginal[0] = 1.0;
+        }
+        marginal
+    }
+
+    /// The expected number of cubes of `color`
+    pub fn mean(&self, color: Color) -> f64 {
+        self.marginal(color).iter().enumerate().map(|(n, p)| n as f64 * p).sum()
+    }
+
+    /// The numbers of cubes of `color` that hold `mass` of the probability, leaving out
+    /// as much on both sides
+    pub fn credible_interval(&self, color: Color, mass: f64) -> RangeInclusive<usize> {
+        let marginal = self.marginal(color);
+        let tail = (1.0 - mass) / 2.0;
+        // A little slack so rounding doesn't move the bounds
+        let epsilon = 1e-12;
+        let mut cumulative = 0.0;
+        let mut low = None;
+        for (n, p) in marginal.iter().enumerate() {
+            cumulative += p;
+            if low.is_none() && cumulative > tail + epsilon {
+                low = Some(n);
+            }
+            if cumulative >= 1.0 - tail - epsilon {
+                return low.unwrap_or(n)..=n;
+            }
+        }
+        low.unwrap_or(0)..=marginal.len() - 1
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn close(a: f64, b: f64) -> bool {
+        (a - b).abs() < 1e-9
+    }
+
+    #[test]
+    fn by_hand() {
+        // Of the bags with 2 or 3 red and up to 3 blue cubes, the likelihoods of drawing 2
+        // red cubes add up to 3.6, 2 of it without blue cubes and 1.6 with 2 red ones
+        let input = crate::parse("Game 1: 2 red\nGame 2: 1 blue\n").unwrap();
+        let (red, blue) = (input.colors.get("red").unwrap(), input.colors.get("blue").unwrap());
+        let posterior = Posterior::new(&input.colors, &input.games[0], &Prior::uniform(3)).unwrap();
+        assert!(close(posterior.marginal(blue)[0], 2.0 / 3.6));
+        assert!(close(posterior.marginal(red)[2], 1.6 / 3.6));
+        assert!(close(posterior.mean(red), 2.0 * 1.6 / 3.6 + 3.0 * 2.0 / 3.6));
+        assert_eq!(posterior.most_likely(), Bag::new(&input.colors, [("red", 2)]));
+        assert_eq!(posterior.credible_interval(red, 0.5), 2..=3);
+        assert_eq!(posterior.credible_interval(blue, 0.5), 0..=1);
+
+        assert!(Posterior::new(&input.colors, &input.games[0], &Prior::uniform(1)).is_none());
+    }
+
+    #[test]
+    fn rounds_narrow_it_down() {
+        let input = crate::parse(include_str!("../demo.txt")).unwrap();
+        let prior = Prior::poisson(8.0, 25);
+        for game in &input.games {
+            let posterior = Posterior::new(&input.colors, game, &prior).unwrap();
+            let (fewest, best) = (game.fewest(), posterior.most_likely());
+            for color in input.colors.iter() {
+                assert!(best.get(color) >= fewest.get(color));
+                assert!(*posterior.credible_interval(color, 0.9).start() >= fewest.get(color));
+                assert!(close(posterior.marginal(color).iter().sum(), 1.0));
+                assert!(posterior.marginal(color)[..fewest.get(color)].iter().all(|&p| p == 0.0));
+            }
+        }
+    }
+}
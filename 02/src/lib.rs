@@ -0,0 +1,228 @@
+use std::collections::{BTreeMap, HashMap};
+
+use aoc_common::{Answer, Param, Params, ParseError, Rng, Solution, Source};
+
+pub mod estimate;
+pub mod query;
+
+pub struct Day02;
+
+impl Solution for Day02 {
+    type Input = Games;
+
+    const PARAMS: &'static [Param] = &[RED, GREEN, BLUE];
+
+    fn check_params(params: &Params) -> Result<(), String> {
+        [RED, GREEN, BLUE].iter().try_for_each(|p| params.get_usize(p).map(|_| ()))
+    }
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse(input)
+    }
+
+    fn part1(input: &Self::Input, params: &Params) -> Result<Answer, String> {
+        let cubes = [RED, GREEN, BLUE].iter().map(|p| Ok((p.name, params.get_usize(p)?))).collect::<Result<Vec<_>, String>>()?;
+        let bag = Bag::new(&input.colors, cubes);
+        Ok(part1(input, &bag))
+    }
+
+    fn part2(input: &Self::Input, _params: &Params) -> Result<Answer, String> {
+        part2(input)
+    }
+
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        generate(rng, size)
+    }
+}
+
+const RED: Param = Param { name: "red", default: 12, help: "red cubes in the bag (part 1)" };
+const GREEN: Param = Param { name: "green", default: 13, help: "green cubes in the bag (part 1)" };
+const BLUE: Param = Param { name: "blue", default: 14, help: "blue cubes in the bag (part 1)" };
+
+/// A color of cube, interned in the `Colors` of the input it was read from
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Color(usize);
+
+/// The names of the colors of an input, in the order they first appear
+#[derive(Debug, Clone, Default)]
+pub struct Colors {
+    names: Vec<String>,
+    ids: HashMap<String, Color>,
+}
+
+impl Colors {
+    pub fn intern(&mut self, name: &str) -> Color {
+        if let Some(&color) = self.ids.get(name) {
+            return color;
+        }
+        let color = Color(self.names.len());
+        self.names.push(name.to_string());
+        self.ids.insert(name.to_string(), color);
+        color
+    }
+
+    pub fn get(&self, name: &str) -> Option<Color> {
+        self.ids.get(name).copied()
+    }
+
+    pub fn name(&self, color: Color) -> &str {
+        &self.names[color.0]
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = Color> {
+        (0..self.names.len()).map(Color)
+    }
+
+    pub fn len(&self) -> usize {
+        self.names.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.names.is_empty()
+    }
+}
+
+/// How many cubes of each color, colors that aren't in it have none
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Bag {
+    cubes: BTreeMap<Color, usize>,
+}
+
+impl Bag {
+    /// A bag with the cubes of the colors that are named in `colors`, the cubes of other
+    /// colors can't show up in its games anyway
+    pub fn new<'a>(colors: &Colors, cubes: impl IntoIterator<Item = (&'a str, usize)>) -> Self {
+        Bag { cubes: cubes.into_iter().filter_map(|(name, n)| Some((colors.get(name)?, n))).collect() }
+    }
+
+    pub fn get(&self, color: Color) -> usize {
+        self.cubes.get(&color).copied().unwrap_or(0)
+    }
+
+    /// The product of the cubes of every color in `colors`, None when it overflows
+    pub fn power(&self, colors: &Colors) -> Option<usize> {
+        colors.iter().try_fold(1usize, |power, color| power.checked_mul(self.get(color)))
+    }
+}
+
+pub struct Game {
+    pub id: usize,
+    /// The cubes shown in each round
+    pub rounds: Vec<Bag>,
+}
+
+impl Game {
+    /// Whether every round could have been drawn from `bag`
+    pub fn check(&self, bag: &Bag) -> bool {
+        self.rounds.iter().all(|round| round.cubes.iter().all(|(&color, &n)| n <= bag.get(color)))
+    }
+
+    /// The smallest bag every round could have been drawn from
+    pub fn fewest(&self) -> Bag {
+        let mut bag = Bag::default();
+        for round in &self.rounds {
+            for (&color, &n) in &round.cubes {
+                let most = bag.cubes.entry(color).or_default();
+                *most = (*most).max(n);
+            }
+        }
+        bag
+    }
+}
+
+pub struct Games {
+    pub colors: Colors,
+    pub games: Vec<Game>,
+}
+
+fn parse_game(source: &Source, colors: &mut Colors, input: &str) -> Result<Game, ParseError> {
+    let (header, data) = source.split_once(input, ": ")?;
+    let id = source.number(source.strip_prefix(header, "Game ")?)?;
+    let rounds = data.split(';').map(|round| parse_round(source, colors, round)).collect::<Result<_, _>>()?;
+    Ok(Game { id, rounds })
+}
+
+// A color shown twice in a round counts twice
+fn parse_round(source: &Source, colors: &mut Colors, input: &str) -> Result<Bag, ParseError> {
+    let mut round = Bag::default();
+    for s in input.split(',') {
+        let (num, color) = source.split_once(s.trim(), " ")?;
+        let color = color.trim();
+        if color.is_empty() || color.contains(char::is_whitespace) {
+            return Err(source.error(color, "a color"));
+        }
+        let cubes = round.cubes.entry(colors.intern(color)).or_default();
+        *cubes = cubes.checked_add(source.number(num)?).ok_or_else(|| source.error(num, "cubes that add up to a 64-bit number"))?;
+    }
+    Ok(round)
+}
+
+pub fn parse(input_str: &str) -> Result<Games, ParseError> {
+    let source = Source::new(input_str);
+    let mut colors = Colors::default();
+    let games = input_str.split('\n')
+        .filter(|l| !l.is_empty())
+        .map(|l| parse_game(&source, &mut colors, l))
+        .collect::<Result<_, _>>()?;
+    Ok(Games { colors, games })
+}
+
+pub fn part1(input: &Games, bag: &Bag) -> Answer {
+    let result: usize = input.games.iter()
+        .filter(|g| g.check(bag))
+        .map(|g| g.id)
+        .sum();
+
+    result.into()
+}
+
+pub fn part2(input: &Games) -> Result<Answer, String> {
+    let mut result = 0usize;
+    for g in &input.games {
+        result = g.fewest().power(&input.colors).and_then(|power| result.checked_add(power))
+            .ok_or_else(|| format!("the powers up to game {} overflow 64 bits", g.id))?;
+    }
+
+    Ok(result.into())
+}
+
+/// `size` games of up to 6 rounds, each round shows some of the colors in a random order
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let mut out = String::new();
+    for id in 1..=size {
+        let rounds: Vec<String> = (0..rng.range(1..=6))
+            .map(|_| {
+                let mut colors = ["red", "green", "blue"];
+                rng.shuffle(&mut colors);
+                let shown = &colors[..rng.range(1..=3) as usize];
+                shown.iter().map(|color| format!("{} {}", rng.range(1..=20), color)).collect::<Vec<_>>().join(", ")
+            })
+            .collect();
+        out += &format!("Game {}: {}\n", id, rounds.join("; "));
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn any_colors() {
+        let input = parse("Game 1: 3 blue, 4 yellow; 1 red, 2 yellow\nGame 2: 1 red, 2 red; 5 purple\n").unwrap();
+        assert_eq!(input.colors.iter().map(|c| input.colors.name(c)).collect::<Vec<_>>(), ["blue", "yellow", "red", "purple"]);
+        let bag = Bag::new(&input.colors, [("red", 3), ("blue", 3), ("yellow", 4), ("green", 9)]);
+        assert_eq!(part1(&input, &bag), Answer::from(1));
+        assert_eq!(input.games[1].fewest(), Bag::new(&input.colors, [("red", 3), ("purple", 5)]));
+        // Every game misses a color, so every power is 0
+        assert_eq!(part2(&input), Ok(Answer::from(0)));
+    }
+
+    #[test]
+    fn too_many_cubes() {
+        let error = parse("Game 1: 18446744073709551615 red, 1 red\n").err().unwrap();
+        assert_eq!((error.line, error.column), (1, 35));
+        let input = parse("Game 1: 4294967296 red, 4294967296 blue, 4294967296 green\n").unwrap();
+        assert!(part2(&input).is_err());
+    }
+}
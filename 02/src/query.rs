@@ -0,0 +1,326 @@
+use std::slice;
+
+use aoc_common::{ParseError, Source};
+
+use crate::{Colors, Game, Games};
+
+/// A number about a game. Colors are kept by name, a color the input doesn't have has
+/// no cubes in any game.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Value {
+    Number(usize),
+    Id,
+    Rounds,
+    /// The most cubes of a color shown in a round, which is also the fewest the bag can hold
+    Max(String),
+    /// The fewest cubes of a color shown in a round, rounds that don't show it count as 0
+    Min(String),
+    /// The sum of the fewest cubes the bag can hold of the colors, of all colors of the
+    /// input when none are named
+    Sum(Vec<String>),
+    /// Like `Sum`, `product()` is the power of part 2
+    Product(Vec<String>),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Comparison {
+    Less,
+    LessOrEqual,
+    Equal,
+    NotEqual,
+    GreaterOrEqual,
+    Greater,
+}
+
+/// Whether a game is selected, e.g. `max(red) <= 12 && (min(blue) >= 2 || rounds > 3)`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Condition {
+    Constant(bool),
+    Compare(Value, Comparison, Value),
+    Not(Box<Condition>),
+    And(Box<Condition>, Box<Condition>),
+    Or(Box<Condition>, Box<Condition>),
+}
+
+impl Value {
+    pub fn eval(&self, colors: &Colors, game: &Game) -> usize {
+        // The fewest cubes of the named colors, the bag is worked out once for all of them
+        let all = |names: &[String]| -> Vec<usize> {
+            let fewest = game.fewest();
+            if names.is_empty() {
+                colors.iter().map(|color| fewest.get(color)).collect()
+            } else {
+                names.iter().map(|name| colors.get(name).map_or(0, |color| fewest.get(color))).collect()
+            }
+        };
+        match self {
+            Value::Number(n) => *n,
+            Value::Id => game.id,
+            Value::Rounds => game.rounds.len(),
+            Value::Max(name) => all(slice::from_ref(name))[0],
+            Value::Min(name) => match colors.get(name) {
+                Some(color) => game.rounds.iter().map(|round| round.get(color)).min().unwrap_or(0),
+                None => 0,
+            },
+            Value::Sum(names) => all(names).into_iter().fold(0, usize::saturating_add),
+            Value::Product(names) => all(names).into_iter().fold(1, usize::saturating_mul),
+        }
+    }
+}
+
+impl Comparison {
+    fn holds(self, a: usize, b: usize) -> bool {
+        match self {
+            Comparison::Less => a < b,
+            Comparison::LessOrEqual => a <= b,
+            Comparison::Equal => a == b,
+            Comparison::NotEqual => a != b,
+            Comparison::GreaterOrEqual => a >= b,
+            Comparison::Greater => a > b,
+        }
+    }
+}
+
+impl Condition {
+    pub fn matches(&self, colors: &Colors, game: &Game) -> bool {
+        match self {
+            Condition::Constant(b) => *b,
+            Condition::Compare(a, comparison, b) => comparison.holds(a.eval(colors, game), b.eval(colors, game)),
+            Condition::Not(condition) => !condition.matches(colors, game),
+            Condition::And(a, b) => a.matches(colors, game) && b.matches(colors, game),
+            Condition::Or(a, b) => a.matches(colors, game) || b.matches(colors, game),
+        }
+    }
+}
+
+/// The games `condition` matches
+pub fn select<'a>(games: &'a Games, condition: &'a Condition) -> impl Iterator<Item = &'a Game> {
+    games.games.iter().filter(|game| condition.matches(&games.colors, game))
+}
+
+const SYMBOLS: [&str; 12] = ["&&", "||", "<=", ">=", "==", "!=", "<", ">", "!", "(", ")", ","];
+
+// How deep conditions can nest, counting `!`, parentheses and each `&&` or `||` of a
+// chain. Parsing, matching and dropping a condition recurse that deep.
+const MAX_DEPTH: usize = 200;
+
+struct Parser<'a> {
+    source: Source<'a>,
+    rest: &'a str,
+    depth: usize,
+}
+
+impl<'a> Parser<'a> {
+    // The next token: a symbol, a word, or an empty slice at the end
+    fn peek(&self) -> &'a str {
+        let rest = self.rest.trim_start();
+        if let Some(symbol) = SYMBOLS.iter().find(|s| rest.starts_with(**s)) {
+            return &rest[..symbol.len()];
+        }
+        let end = rest.find(|c: char| c.is_whitespace() || "&|<>=!(),".contains(c)).unwrap_or(rest.len());
+        // A character that is no symbol on its own, like a single `&`
+        let end = if end == 0 { rest.chars().next().map_or(0, char::len_utf8) } else { end };
+        &rest[..end]
+    }
+
+    fn next(&mut self) -> &'a str {
+        let token = self.peek();
+        self.rest = &self.rest[self.rest.len() - self.rest.trim_start().len() + token.len()..];
+        token
+    }
+
+    fn accept(&mut self, symbol: &str) -> bool {
+        let found = self.peek() == symbol;
+        if found {
+            self.next();
+        }
+        found
+    }
+
+    fn expect(&mut self, symbol: &str) -> Result<(), ParseError> {
+        let token = self.next();
+        if token == symbol {
+            Ok(())
+        } else {
+            Err(self.source.error(token, format!("`{}`", symbol)))
+        }
+    }
+
+    fn end(&mut self) -> Result<(), ParseError> {
+        let token = self.peek();
+        if token.is_empty() {
+            Ok(())
+        } else {
+            Err(self.source.error(token, "end of query"))
+        }
+    }
+
+    // Goes one level deeper for `token`, the callers restore `depth` when they are done
+    fn deeper(&mut self, token: &'a str) -> Result<(), ParseError> {
+        self.depth += 1;
+        if self.depth > MAX_DEPTH {
+            return Err(self.source.error(token, format!("conditions nested at most {} deep", MAX_DEPTH)));
+        }
+        Ok(())
+    }
+
+    fn or(&mut self) -> Result<Condition, ParseError> {
+        let depth = self.depth;
+        let mut condition = self.and()?;
+        while self.peek() == "||" {
+            self.deeper(self.peek())?;
+            self.next();
+            condition = Condition::Or(Box::new(condition), Box::new(self.and()?));
+        }
+        self.depth = depth;
+        Ok(condition)
+    }
+
+    fn and(&mut self) -> Result<Condition, ParseError> {
+        let depth = self.depth;
+        let mut condition = self.unary()?;
+        while self.peek() == "&&" {
+            self.deeper(self.peek())?;
+            self.next();
+            condition = Condition::And(Box::new(condition), Box::new(self.unary()?));
+        }
+        self.depth = depth;
+        Ok(condition)
+    }
+
+    fn unary(&mut self) -> Result<Condition, ParseError> {
+        let depth = self.depth;
+        let token = self.peek();
+        if self.accept("!") {
+            self.deeper(token)?;
+            let condition = self.unary()?;
+            self.depth = depth;
+            return Ok(Condition::Not(Box::new(condition)));
+        }
+        if self.accept("(") {
+            self.deeper(token)?;
+            let condition = self.or()?;
+            self.expect(")")?;
+            self.depth = depth;
+            return Ok(condition);
+        }
+        if self.accept("true") {
+            return Ok(Condition::Constant(true));
+        }
+        if self.accept("false") {
+            return Ok(Condition::Constant(false));
+        }
+
+        let a = self.value()?;
+        let token = self.next();
+        let comparison = match token {
+            "<" => Comparison::Less,
+            "<=" => Comparison::LessOrEqual,
+            "==" => Comparison::Equal,
+            "!=" => Comparison::NotEqual,
+            ">=" => Comparison::GreaterOrEqual,
+            ">" => Comparison::Greater,
+            _ => return Err(self.source.error(token, "a comparison")),
+        };
+        Ok(Condition::Compare(a, comparison, self.value()?))
+    }
+
+    fn color(&mut self) -> Result<String, ParseError> {
+        let token = self.next();
+        if token.is_empty() || SYMBOLS.contains(&token) {
+            return Err(self.source.error(token, "a color"));
+        }
+        Ok(token.to_string())
+    }
+
+    fn value(&mut self) -> Result<Value, ParseError> {
+        let token = self.next();
+        let value = match token {
+            "id" => Value::Id,
+            "rounds" => Value::Rounds,
+            "max" | "min" => {
+                self.expect("(")?;
+                let color = self.color()?;
+                self.expect(")")?;
+                if token == "max" { Value::Max(color) } else { Value::Min(color) }
+            }
+            "sum" | "product" => {
+                self.expect("(")?;
+                let mut colors = Vec::new();
+                if !self.accept(")") {
+                    colors.push(self.color()?);
+                    while self.accept(",") {
+                        colors.push(self.color()?);
+                    }
+                    self.expect(")")?;
+                }
+                if token == "sum" { Value::Sum(colors) } else { Value::Product(colors) }
+            }
+            _ if token.starts_with(|c: char| c.is_ascii_digit()) => Value::Number(self.source.number(token)?),
+            _ => return Err(self.source.error(token, "a number, `id`, `rounds`, `max`, `min`, `sum` or `product`")),
+        };
+        Ok(value)
+    }
+}
+
+pub fn parse_condition(query: &str) -> Result<Condition, ParseError> {
+    let mut parser = Parser { source: Source::new(query), rest: query, depth: 0 };
+    let condition = parser.or()?;
+    parser.end()?;
+    Ok(condition)
+}
+
+pub fn parse_value(query: &str) -> Result<Value, ParseError> {
+    let mut parser = Parser { source: Source::new(query), rest: query, depth: 0 };
+    let value = parser.value()?;
+    parser.end()?;
+    Ok(value)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const DEMO: &str = include_str!("../demo.txt");
+
+    fn total(query: &str, value: &str) -> usize {
+        let games = crate::parse(DEMO).unwrap();
+        let (condition, value) = (parse_condition(query).unwrap(), parse_value(value).unwrap());
+        select(&games, &condition).map(|game| value.eval(&games.colors, game)).sum()
+    }
+
+    #[test]
+    fn parts_as_queries() {
+        assert_eq!(total("max(red) <= 12 && max(green) <= 13 && max(blue) <= 14", "id"), 8);
+        assert_eq!(total("true", "product()"), 2286);
+        assert_eq!(total("true", "product(red, green, blue)"), 2286);
+        assert_eq!(total("!(max(red)>12 || max(green)>13 || max(blue)>14)", "1"), 3);
+        assert_eq!(total("min(blue) >= 1 && rounds == 3", "sum(red, blue)"), 1 + 4);
+        assert_eq!(total("max(purple) == 0 && id != 2", "id"), 13);
+    }
+
+    #[test]
+    fn points_at_errors() {
+        let error = parse_condition("max(red) <= 12 && rounds 3").unwrap_err();
+        assert_eq!((error.column, error.expected.as_str()), (26, "a comparison"));
+        let error = parse_condition("max(red <= 12").unwrap_err();
+        assert_eq!((error.column, error.found.as_str()), (9, "`<`"));
+        let error = parse_condition("(rounds > 1").unwrap_err();
+        assert_eq!(error.found, "end of input");
+        assert!(parse_value("id > 1").is_err());
+    }
+
+    #[test]
+    fn nesting_depth() {
+        let nested = |open: &str, depth: usize, close: &str| format!("{}id > 1{}", open.repeat(depth), close.repeat(depth));
+        assert!(parse_condition(&nested("(", MAX_DEPTH, ")")).is_ok());
+        assert!(parse_condition(&nested("!", MAX_DEPTH, "")).is_ok());
+        assert!(parse_condition(&nested("!(", MAX_DEPTH / 2, ")")).is_ok());
+        let error = parse_condition(&nested("(", MAX_DEPTH + 1, ")")).unwrap_err();
+        assert_eq!((error.column, error.expected.as_str()), (MAX_DEPTH + 1, "conditions nested at most 200 deep"));
+        assert!(parse_condition(&nested("!", 100_000, "")).is_err());
+        assert!(parse_condition(&vec!["id > 1"; MAX_DEPTH + 1].join(" && ")).is_ok());
+        assert!(parse_condition(&vec!["id > 1"; MAX_DEPTH + 2].join(" || ")).is_err());
+        assert!(parse_condition(&vec!["id > 1"; 100_000].join(" && ")).is_err());
+    }
+}
@@ -0,0 +1,236 @@
+use std::{ops::Range, str};
+
+use aoc_common::{Answer, Params, ParseError, Pos, Rng, Solution, Source};
+
+pub struct Day03;
+
+impl Solution for Day03 {
+    type Input = Schematic;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse(input)
+    }
+
+    fn part1(input: &Self::Input, _params: &Params) -> Result<Answer, String> {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input, _params: &Params) -> Result<Answer, String> {
+        part2(input)
+    }
+
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        generate(rng, size)
+    }
+}
+
+/// A number of the schematic, on one row
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Number {
+    pub value: usize,
+    pub row: usize,
+    pub cols: Range<usize>,
+}
+
+/// Anything that isn't a digit or `.`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Symbol {
+    pub symbol: u8,
+    pub pos: Pos,
+}
+
+/// The numbers and symbols of a schematic, and which are next to each other, also
+/// diagonally
+pub struct Schematic {
+    pub numbers: Vec<Number>,
+    pub symbols: Vec<Symbol>,
+    /// The rows and the width of the longest one, every cell is within them
+    pub height: usize,
+    pub width: usize,
+    /// The numbers of each row by column
+    rows: Vec<Vec<usize>>,
+    /// The symbols next to each number
+    number_edges: Vec<Vec<usize>>,
+    /// The numbers next to each symbol, in the order of `numbers`
+    symbol_edges: Vec<Vec<usize>>,
+}
+
+/// Gears are `symbol`s with exactly `arity` numbers next to them, `reduce` turns those
+/// numbers into the ratio of the gear, `None` if it doesn't fit in 64 bits
+#[derive(Clone, Copy)]
+pub struct GearRule {
+    pub symbol: u8,
+    pub arity: usize,
+    pub reduce: fn(&[usize]) -> Option<usize>,
+}
+
+/// The gears of part 2
+pub const GEAR: GearRule = GearRule { symbol: b'*', arity: 2, reduce: |numbers| numbers.iter().try_fold(1usize, |ratio, &n| ratio.checked_mul(n)) };
+
+impl Schematic {
+    fn new(source: &Source, lines: &[&str]) -> Result<Self, ParseError> {
+        let mut numbers = Vec::new();
+        let mut symbols = Vec::new();
+        let mut rows = vec![Vec::new(); lines.len()];
+        for (row, line) in lines.iter().enumerate() {
+            let bytes = line.as_bytes();
+            let mut col = 0;
+            while col < bytes.len() {
+                let len = bytes[col..].iter().take_while(|c| c.is_ascii_digit()).count();
+                if len > 0 {
+                    let digits = &line[col..col + len];
+                    let value = digits.parse().map_err(|_| source.error(digits, "a number that fits in 64 bits"))?;
+                    rows[row].push(numbers.len());
+                    numbers.push(Number { value, row, cols: col..col + len });
+                } else if bytes[col] != b'.' {
+                    symbols.push(Symbol { symbol: bytes[col], pos: Pos::new(row, col) });
+                }
+                col += len.max(1);
+            }
+        }
+
+        let mut schematic = Schematic { number_edges: vec![Vec::new(); numbers.len()], symbol_edges: Vec::new(), numbers, symbols, height: lines.len(), width: lines.iter().map(|l| l.len()).max().unwrap_or(0), rows };
+        schematic.symbol_edges = schematic.symbols.iter().map(|symbol| {
+            let Pos { row, col } = symbol.pos;
+            let mut edges: Vec<usize> = (row.saturating_sub(1)..=row + 1).flat_map(|row| schematic.numbers_near(row, col.saturating_sub(1)..col + 2)).collect();
+            edges.sort();
+            edges
+        }).collect();
+        for (s, edges) in schematic.symbol_edges.iter().enumerate() {
+            for &n in edges {
+                schematic.number_edges[n].push(s);
+            }
+        }
+        Ok(schematic)
+    }
+
+    // The numbers of `row` with a digit in `cols`, found by bisecting the row
+    fn numbers_near(&self, row: usize, cols: Range<usize>) -> impl Iterator<Item = usize> + '_ {
+        let numbers = self.rows.get(row).map_or(&[][..], |numbers| &numbers[..]);
+        let first = numbers.partition_point(|&n| self.numbers[n].cols.end <= cols.start);
+        numbers[first..].iter().copied().take_while(move |&n| self.numbers[n].cols.start < cols.end)
+    }
+
+    /// The number with a digit at `pos`, as an index into `numbers`
+    pub fn number_at(&self, pos: Pos) -> Option<usize> {
+        self.numbers_near(pos.row, pos.col..pos.col + 1).next()
+    }
+
+    /// The symbols next to a number, as indexes into `symbols`
+    pub fn symbols_of(&self, number: usize) -> &[usize] {
+        &self.number_edges[number]
+    }
+
+    /// The numbers next to a symbol, as indexes into `numbers`
+    pub fn numbers_of(&self, symbol: usize) -> &[usize] {
+        &self.symbol_edges[symbol]
+    }
+
+    /// The numbers next to a symbol
+    pub fn part_numbers(&self) -> impl Iterator<Item = &Number> {
+        self.numbers.iter().zip(&self.number_edges).filter(|(_, edges)| !edges.is_empty()).map(|(number, _)| number)
+    }
+
+    /// The gears by `rule` with their ratios
+    pub fn gears(&self, rule: GearRule) -> impl Iterator<Item = (&Symbol, Option<usize>)> {
+        self.symbols.iter().zip(&self.symbol_edges).filter(move |(symbol, edges)| symbol.symbol == rule.symbol && edges.len() == rule.arity).map(move |(symbol, edges)| {
+            let values: Vec<usize> = edges.iter().map(|&n| self.numbers[n].value).collect();
+            (symbol, (rule.reduce)(&values))
+        })
+    }
+}
+
+/// Lines can have different widths, the cells missing at their ends are empty. Empty
+/// lines are rows without anything on them, except at the end.
+pub fn parse(input_str: &str) -> Result<Schematic, ParseError> {
+    let source = Source::new(input_str);
+    let mut lines: Vec<&str> = input_str.lines().map(|l| l.trim_end_matches('\r')).collect();
+    while lines.last().is_some_and(|l| l.is_empty()) {
+        lines.pop();
+    }
+    for line in &lines {
+        if let Some((i, _)) = line.char_indices().find(|(_, c)| !c.is_ascii_graphic()) {
+            return Err(source.error(&line[i..], "`.`, a digit or a symbol"));
+        }
+    }
+    Schematic::new(&source, &lines)
+}
+
+pub fn part1(schematic: &Schematic) -> Result<Answer, String> {
+    let result = schematic.part_numbers().try_fold(0usize, |sum, number| sum.checked_add(number.value));
+    result.map(Answer::from).ok_or_else(|| "the part numbers don't add up to a 64-bit number".to_string())
+}
+
+pub fn part2(schematic: &Schematic) -> Result<Answer, String> {
+    let result = schematic.gears(GEAR).try_fold(0usize, |sum, (_, ratio)| sum.checked_add(ratio?));
+    result.map(Answer::from).ok_or_else(|| "the gear ratios don't add up to a 64-bit number".to_string())
+}
+
+/// A `size` by `size` schematic with numbers of up to three digits and symbols scattered
+/// around them
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    const SYMBOLS: &[u8] = b"*#+$/@=%-&";
+
+    let mut out = String::new();
+    for _ in 0..size {
+        let mut line = vec![b'.'; size];
+        let mut col = 0;
+        while col < size {
+            if rng.chance(0.15) {
+                let len = (rng.range(1..=3) as usize).min(size - col);
+                line[col] = b'1' + rng.below(9) as u8;
+                for digit in &mut line[col + 1..col + len] {
+                    *digit = b'0' + rng.below(10) as u8;
+                }
+                col += len + 1;
+            } else {
+                if rng.chance(0.08) {
+                    line[col] = *rng.pick(SYMBOLS);
+                }
+                col += 1;
+            }
+        }
+        out += str::from_utf8(&line).unwrap();
+        out.push('\n');
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn graph() {
+        let schematic = parse(include_str!("../demo.txt")).unwrap();
+        let star = schematic.symbols.iter().position(|s| s.pos == Pos::new(1, 3)).unwrap();
+        let values = |numbers: &[usize]| numbers.iter().map(|&n| schematic.numbers[n].value).collect::<Vec<_>>();
+        assert_eq!(values(schematic.numbers_of(star)), [467, 35]);
+        assert_eq!(schematic.number_at(Pos::new(2, 3)).map(|n| schematic.numbers[n].value), Some(35));
+        assert_eq!(schematic.number_at(Pos::new(2, 4)), None);
+        assert!(schematic.symbols_of(schematic.number_at(Pos::new(0, 5)).unwrap()).is_empty());
+
+        // Any symbol next to a single number, counting the numbers
+        let lonely = GearRule { symbol: b'*', arity: 1, reduce: |numbers| Some(numbers.len()) };
+        assert_eq!(schematic.gears(lonely).map(|(symbol, ratio)| (symbol.pos, ratio)).collect::<Vec<_>>(), [(Pos::new(4, 3), Some(1))]);
+        let sum = GearRule { symbol: b'*', arity: 2, reduce: |numbers| numbers.iter().sum::<usize>().into() };
+        assert_eq!(schematic.gears(sum).map(|(_, ratio)| ratio.unwrap()).sum::<usize>(), 467 + 35 + 755 + 598);
+    }
+
+    #[test]
+    fn ratios_past_64_bits() {
+        let schematic = parse("99999999999*99999999999\n").unwrap();
+        assert_eq!(schematic.gears(GEAR).map(|(_, ratio)| ratio).collect::<Vec<_>>(), [None]);
+        assert!(part2(&schematic).is_err());
+        assert!(part1(&parse("18446744073709551615*1\n").unwrap()).is_err());
+    }
+
+    #[test]
+    fn ragged_lines() {
+        let schematic = parse("12.\r\n..*...7\r\n\r\n3\r\n.......$5\r\n\r\n\r\n").unwrap();
+        assert_eq!((schematic.height, schematic.width), (5, 9));
+        assert_eq!(part1(&schematic), Ok(Answer::from(12 + 5)));
+        assert_eq!(schematic.symbols.iter().map(|s| s.pos).collect::<Vec<_>>(), [Pos::new(1, 2), Pos::new(4, 7)]);
+        assert_eq!(parse("1.\n.*\n2 3\n").err().map(|e| (e.line, e.column)), Some((3, 2)));
+    }
+}
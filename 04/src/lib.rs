@@ -0,0 +1,107 @@
+use std::collections::BTreeSet;
+
+use aoc_common::{Answer, Params, ParseError, Rng, Solution, Source};
+
+pub struct Day04;
+
+impl Solution for Day04 {
+    type Input = Vec<Card>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse(input)
+    }
+
+    fn part1(input: &Self::Input, _params: &Params) -> Result<Answer, String> {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input, _params: &Params) -> Result<Answer, String> {
+        part2(input)
+    }
+
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        generate(rng, size)
+    }
+}
+
+pub struct Card {
+    winning: BTreeSet<u8>,
+    have: BTreeSet<u8>
+}
+
+impl Card {
+    fn count_winning(&self) -> usize {
+        self.have.iter().filter(|n| self.winning.contains(n)).count()
+    }
+
+    // None when it doesn't fit in 64 bits
+    fn score(&self) -> Option<usize> {
+        let winning_count = self.count_winning();
+        if winning_count > 0 {
+            1usize.checked_shl(winning_count as u32 - 1)
+        } else {
+            Some(0)
+        }
+    }
+}
+
+fn parse_line(source: &Source, input: &str) -> Result<Card, ParseError> {
+    let (_, input) = source.split_once(input, ": ")?;
+    let (winning_input, have_input) = source.split_once(input, " | ")?;
+    let winning = winning_input.split(" ").filter(|n| !n.is_empty()).map(|n| source.number(n)).collect::<Result<_, _>>()?;
+    let have = have_input.split(" ").filter(|n| !n.is_empty()).map(|n| source.number(n)).collect::<Result<_, _>>()?;
+    Ok(Card {
+        winning,
+        have
+    })
+}
+
+pub fn parse(input_str: &str) -> Result<Vec<Card>, ParseError> {
+    let source = Source::new(input_str);
+    input_str.split("\n").filter(|l| !l.is_empty()).map(|l| parse_line(&source, l)).collect()
+}
+
+pub fn part1(input: &[Card]) -> Result<Answer, String> {
+    let result = input.iter().try_fold(0usize, |sum, c| sum.checked_add(c.score()?))
+        .ok_or("the points don't fit in 64 bits")?;
+
+    Ok(result.into())
+}
+
+pub fn part2(input: &[Card]) -> Result<Answer, String> {
+    let mut nums: Vec<usize> = vec![1; input.len()];
+
+    for (i, c) in input.iter().enumerate() {
+        let winning = c.count_winning();
+        if i + winning >= input.len() {
+            return Err(format!("card {} wins copies of cards past the end of the table", i + 1));
+        }
+
+        for j in i+1..i+1+winning {
+            nums[j] = nums[j].checked_add(nums[i]).ok_or_else(|| format!("the copies of card {} don't fit in 64 bits", j + 1))?;
+        }
+    }
+
+    let result2 = nums.iter().try_fold(0usize, |sum, &n| sum.checked_add(n)).ok_or("the cards don't fit in 64 bits")?;
+
+    Ok(result2.into())
+}
+
+/// `size` cards with 10 winning numbers and 25 numbers you have. Most cards win nothing,
+/// so the copies of part 2 grow linearly rather than exponentially.
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let mut out = String::new();
+    for i in 0..size {
+        let mut numbers: Vec<u8> = (1..=99).collect();
+        rng.shuffle(&mut numbers);
+        let (winning, others) = numbers.split_at(10);
+        // Cards never win copies past the end of the table
+        let matching = if rng.chance(0.7) { 0 } else { rng.range(1..=4) as usize }.min(size - 1 - i);
+        let mut have: Vec<u8> = winning[..matching].iter().chain(&others[..25 - matching]).copied().collect();
+        rng.shuffle(&mut have);
+
+        let column = |numbers: &[u8]| numbers.iter().map(|n| format!("{:>2}", n)).collect::<Vec<_>>().join(" ");
+        out += &format!("Card {:>3}: {} | {}\n", i + 1, column(winning), column(&have));
+    }
+    out
+}
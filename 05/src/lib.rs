@@ -0,0 +1,214 @@
+use std::{ops::Range, cmp::min};
+
+use aoc_common::{Answer, Params, ParseError, Rng, Solution, Source};
+
+pub struct Day05;
+
+impl Solution for Day05 {
+    type Input = Almanac;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse(input)
+    }
+
+    fn part1(input: &Self::Input, _params: &Params) -> Result<Answer, String> {
+        Ok(part1(input))
+    }
+
+    fn part2(input: &Self::Input, _params: &Params) -> Result<Answer, String> {
+        Ok(part2(input))
+    }
+
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        generate(rng, size)
+    }
+}
+
+struct MapRange {
+    range: Range<u64>,
+    shift: i64
+}
+
+struct Map {
+    ranges: Vec<MapRange>
+}
+
+impl Map {
+    fn translate(&self, input: u64) -> u64 {
+        for r in &self.ranges {
+            if r.range.contains(&input) {
+                return input.wrapping_add_signed(r.shift);
+            }
+        }
+        input
+    }
+
+    fn translate_range(&self, range: Range<u64>) -> Vec<Range<u64>> {
+        let start = range.start;
+        let mut result = vec![];
+        let mut cur = start;
+        for r in self.ranges.iter().skip_while(|r| r.range.end < start) {
+            if r.range.start > cur {
+                result.push(cur..min(range.end, r.range.start));
+                cur = r.range.start;
+            }
+            if cur >= range.end {
+                break;
+            }
+            result.push(cur.wrapping_add_signed(r.shift)..min(range.end, r.range.end).wrapping_add_signed(r.shift));
+            cur = r.range.end;
+            if cur >= range.end {
+                break;
+            }
+        }
+        if cur < range.end {
+            result.push(cur..range.end);
+        }
+        result
+    }
+}
+
+fn parse_map(source: &Source, input: &str) -> Result<Map, ParseError> {
+    let (_, input) = source.split_once(input, "\n")?;
+    let mut ranges: Vec<MapRange> = input.split("\n").filter(|l| !l.is_empty()).map(|l| {
+        let (first_start, rest) = source.split_once(l, " ")?;
+        let (second_start, len) = source.split_once(rest, " ")?;
+        let first_start: u64 = source.number(first_start)?;
+        let second_start: u64 = source.number(second_start)?;
+        let len: u64 = source.number(len)?;
+        // Both ranges have to end within u64 and the shift between them has to fit an i64
+        let end = second_start.checked_add(len).filter(|_| first_start.checked_add(len).is_some());
+        let shift = i64::try_from(first_start).ok().zip(i64::try_from(second_start).ok()).and_then(|(first, second)| first.checked_sub(second));
+        let (Some(end), Some(shift)) = (end, shift) else {
+            return Err(source.error(l, "a range that fits in 64 bits"));
+        };
+        Ok(MapRange {
+            range: second_start..end,
+            shift
+        })
+    }).collect::<Result<_, ParseError>>()?;
+    ranges.sort_by_key(|a| a.range.start);
+    Ok(Map { ranges })
+}
+
+pub struct Almanac {
+    seeds: Vec<u64>,
+    maps: Vec<Map>,
+}
+
+pub fn parse(input_str: &str) -> Result<Almanac, ParseError> {
+    let source = Source::new(input_str);
+    let (init_str, maps_str) = source.split_once(input_str, "\n\n")?;
+
+    let seeds: Vec<&str> = source.strip_prefix(init_str, "seeds: ")?.split(" ").collect();
+    let init: Vec<u64> = seeds.iter().map(|n| source.number(n)).collect::<Result<_, _>>()?;
+    // Part 2 reads the seeds as pairs of start and length
+    for (pair, numbers) in seeds.chunks(2).zip(init.chunks(2)) {
+        match numbers {
+            [start, len] if start.checked_add(*len).is_none() => return Err(source.error(pair[1], "a range that fits in 64 bits")),
+            [_] => return Err(source.error_after(pair[0], "a length for the seed range")),
+            _ => {}
+        }
+    }
+
+    let maps = maps_str
+        .split("\n\n")
+        .filter(|l| !l.is_empty())
+        .map(|m| parse_map(&source, m))
+        .collect::<Result<Vec<Map>, _>>()?;
+
+    Ok(Almanac { seeds: init, maps })
+}
+
+pub fn part1(input: &Almanac) -> Answer {
+    let mut min = u64::MAX;
+
+    for i in &input.seeds {
+        let mut cur = *i;
+        for m in &input.maps {
+            cur = m.translate(cur);
+        }
+        if cur < min {
+            min = cur;
+        }
+    }
+
+    min.into()
+}
+
+pub fn part2(input: &Almanac) -> Answer {
+    let init = &input.seeds;
+
+    let mut min = u64::MAX;
+
+    for i in (0..init.len()).step_by(2) {
+        let seeds = init[i]..init[i]+init[i+1];
+        let mut cur = vec![seeds];
+        for m in &input.maps {
+            let mut new = vec![];
+            for r in cur {
+                let mut result = m.translate_range(r);
+                new.append(&mut result);
+            }
+            cur = new;
+        }
+        for r in cur {
+            if r.start < min {
+                min = r.start;
+            }
+        }
+    }
+
+    min.into()
+}
+
+/// `size` seed ranges and seven maps of `size` ranges each. A map cuts the numbers below
+/// 2^32 into pieces and moves them around, leaving a few where they are.
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    const STEPS: [&str; 8] = ["seed", "soil", "fertilizer", "water", "light", "temperature", "humidity", "location"];
+    const SPACE: i64 = 1 << 32;
+
+    let seeds: Vec<String> = (0..size).map(|_| format!("{} {}", rng.range(0..=SPACE - 1), rng.range(1..=1 << 28))).collect();
+    let mut out = format!("seeds: {}\n", seeds.join(" "));
+
+    for names in STEPS.windows(2) {
+        let mut cuts: Vec<i64> = (0..size).map(|_| rng.range(1..=SPACE - 1)).collect();
+        cuts.extend([0, SPACE]);
+        cuts.sort();
+        cuts.dedup();
+        let mut pieces: Vec<(i64, i64)> = cuts.windows(2).map(|w| (w[0], w[1] - w[0])).collect();
+        rng.shuffle(&mut pieces);
+
+        out += &format!("\n{}-to-{} map:\n", names[0], names[1]);
+        // The pieces in their new order are packed from 0
+        let mut destination = 0;
+        for (source, len) in pieces {
+            if rng.chance(0.9) {
+                out += &format!("{} {} {}\n", destination, source, len);
+            }
+            destination += len;
+        }
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn ranges_past_64_bits() {
+        let error = parse("seeds: 1 2\n\nx map:\n18446744073709551615 18446744073709551615 5\n").err().unwrap();
+        assert_eq!((error.line, error.column, error.expected.as_str()), (4, 1, "a range that fits in 64 bits"));
+        assert!(parse("seeds: 1 2\n\nx map:\n0 9223372036854775808 5\n").is_err());
+        assert!(parse("seeds: 1 2\n\nx map:\n9223372036854775807 0 5\n").is_ok());
+    }
+
+    #[test]
+    fn seeds_in_ranges() {
+        let error = parse("seeds: 1 2 3\n\nx map:\n1 2 3\n").err().unwrap();
+        assert_eq!((error.line, error.column, error.expected.as_str()), (1, 13, "a length for the seed range"));
+        let error = parse("seeds: 18446744073709551615 5\n\nx map:\n1 2 3\n").err().unwrap();
+        assert_eq!((error.line, error.column, error.expected.as_str()), (1, 29, "a range that fits in 64 bits"));
+    }
+}
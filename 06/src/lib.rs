@@ -0,0 +1,99 @@
+use aoc_common::{Answer, Params, ParseError, Rng, Solution, Source};
+
+pub struct Day06;
+
+impl Solution for Day06 {
+    type Input = Races;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse(input)
+    }
+
+    fn part1(input: &Self::Input, _params: &Params) -> Result<Answer, String> {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input, _params: &Params) -> Result<Answer, String> {
+        Ok(part2(input))
+    }
+
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        generate(rng, size)
+    }
+}
+
+pub struct Races {
+    times: Vec<usize>,
+    distances: Vec<usize>,
+    // For part 2 the spaces between the numbers are ignored
+    time: usize,
+    distance: usize,
+}
+
+// None when the distance doesn't fit in 64 bits, which beats any record
+fn calc(time: usize, hold_time: usize, ) -> Option<usize> {
+    let run_time = time - hold_time;
+    run_time.checked_mul(hold_time)
+}
+
+pub fn parse(input: &str) -> Result<Races, ParseError> {
+    let source = Source::new(input);
+    let (times_in, distances_in) = source.split_once(input, "\n")?;
+    let times_in = source.strip_prefix(times_in, "Time:")?;
+    let distances_in = source.strip_prefix(distances_in, "Distance:")?;
+
+    let times = times_in.split_whitespace().map(|n| source.number(n)).collect::<Result<Vec<usize>, _>>()?;
+    let distances = distances_in.split_whitespace().map(|n| source.number(n)).collect::<Result<Vec<usize>, _>>()?;
+
+    let time:usize = times_in.trim().replace(" ", "").parse().map_err(|_| source.error(times_in, "a number"))?;
+    let distance:usize = distances_in.trim().replace(" ", "").parse().map_err(|_| source.error(distances_in, "a number"))?;
+
+    Ok(Races { times, distances, time, distance })
+}
+
+// Analytical solution exists but the numbers are small enough for brute force to work almost instantly
+pub fn part1(input: &Races) -> Result<Answer, String> {
+    let mut result: usize = 1;
+
+    for (time, dist) in input.times.iter().zip(input.distances.iter()) {
+        let mut count = 0;
+        for i in 1..*time {
+            if calc(*time, i).is_none_or(|d| d > *dist) {
+                count += 1;
+            }
+        }
+        result = result.checked_mul(count).ok_or("the product of the ways to win doesn't fit in 64 bits")?;
+    }
+
+    Ok(result.into())
+}
+
+pub fn part2(input: &Races) -> Answer {
+    let (time, distance) = (input.time, input.distance);
+
+    let mut count = 0;
+    for i in 1..time {
+        if calc(time, i).is_none_or(|d| d > distance) {
+            count += 1;
+        }
+    }
+
+    count.into()
+}
+
+/// `size` races, but no more than 4: part 2 tries every button time of the races glued
+/// together, which gets too slow past 8 digits
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let record = |time: usize| (time / 2) * (time - time / 2);
+    loop {
+        let times: Vec<usize> = (0..size.clamp(1, 4)).map(|_| rng.range(7..=99) as usize).collect();
+        let distances: Vec<usize> = times.iter().map(|&time| rng.range(1..=record(time) as i64 - 1) as usize).collect();
+
+        // The glued race has to be winnable too
+        let glue = |numbers: &[usize]| numbers.iter().map(|n| n.to_string()).collect::<String>().parse::<usize>().unwrap();
+        if glue(&distances) < record(glue(&times)) {
+            let column = |numbers: &[usize]| numbers.iter().map(|n| format!("{:>5}", n)).collect::<String>();
+            return format!("Time:    {}\nDistance:{}\n", column(&times), column(&distances));
+        }
+    }
+}
@@ -0,0 +1,261 @@
+use std::{collections::BTreeMap, cmp::Ordering};
+
+use aoc_common::{Answer, Params, ParseError, Rng, Solution, Source};
+
+pub struct Day07;
+
+impl Solution for Day07 {
+    type Input = Hands;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse(input)
+    }
+
+    fn part1(input: &Self::Input, _params: &Params) -> Result<Answer, String> {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input, _params: &Params) -> Result<Answer, String> {
+        part2(input)
+    }
+
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        generate(rng, size)
+    }
+}
+
+// The jokers change both the card order and the scoring, so both readings are kept
+pub struct Hands {
+    hands: Vec<Hand>,
+    hands2: Vec<Hand2>,
+}
+
+
+
+#[derive(PartialEq, PartialOrd, Eq, Debug, Clone, Copy, Ord)]
+enum Card {
+    A, K, Q, J, T, N9, N8, N7, N6, N5, N4, N3, N2
+}
+
+fn parse_hand(source: &Source, s: &str) -> Result<Vec<Card>, ParseError> {
+    s.char_indices().map(|(i, c)| parse_card(c).ok_or_else(|| source.error(&s[i..], "a card"))).collect()
+}
+
+fn parse_card(c: char) -> Option<Card> {
+    match c {
+        'A' => Some(Card::A),
+        'K' => Some(Card::K),
+        'Q' => Some(Card::Q),
+        'J' => Some(Card::J),
+        'T' => Some(Card::T),
+        '9' => Some(Card::N9),
+        '8' => Some(Card::N8),
+        '7' => Some(Card::N7),
+        '6' => Some(Card::N6),
+        '5' => Some(Card::N5),
+        '4' => Some(Card::N4),
+        '3' => Some(Card::N3),
+        '2' => Some(Card::N2),
+        _ => None
+    }
+}
+
+#[derive(PartialEq, PartialOrd, Eq, Debug, Clone, Copy, Ord)]
+enum Card2 {
+    A, K, Q, T, N9, N8, N7, N6, N5, N4, N3, N2, J
+}
+
+fn parse_hand2(source: &Source, s: &str) -> Result<Vec<Card2>, ParseError> {
+    s.char_indices().map(|(i, c)| parse_card2(c).ok_or_else(|| source.error(&s[i..], "a card"))).collect()
+}
+
+fn parse_card2(c: char) -> Option<Card2> {
+    match c {
+        'A' => Some(Card2::A),
+        'K' => Some(Card2::K),
+        'Q' => Some(Card2::Q),
+        'J' => Some(Card2::J),
+        'T' => Some(Card2::T),
+        '9' => Some(Card2::N9),
+        '8' => Some(Card2::N8),
+        '7' => Some(Card2::N7),
+        '6' => Some(Card2::N6),
+        '5' => Some(Card2::N5),
+        '4' => Some(Card2::N4),
+        '3' => Some(Card2::N3),
+        '2' => Some(Card2::N2),
+        _ => None
+    }
+}
+
+#[derive(PartialEq, Eq, Clone)]
+struct Hand {
+    cards: Vec<Card>,
+    bid: usize,
+}
+
+impl Hand {
+    fn of_a_kind(&self) -> Vec<usize> {
+        let mut counters = BTreeMap::<Card, usize>::new();
+        for card in self.cards.iter() {
+            *(counters.entry(*card).or_default()) += 1;
+        }
+        let mut result: Vec<usize> = counters.values().filter(|n| **n > 0).copied().collect();
+        result.sort_by(|a,b| b.cmp(a));
+        result
+    }
+
+    fn score(&self) -> usize {
+        let of_a_kind = self.of_a_kind();
+        if of_a_kind[0] == 5 {
+            110
+        } else if of_a_kind[0] == 4 {
+            100
+        } else if of_a_kind[0] == 3 && of_a_kind[1] == 2 {
+            90
+        } else if of_a_kind[0] == 3 {
+            80
+        } else if of_a_kind[0] == 2 && of_a_kind[1] == 2{
+            70
+        } else if of_a_kind[0] == 2 {
+            60
+        } else {
+            0
+        }
+    }
+}
+
+impl PartialOrd for Hand {
+    fn partial_cmp(&self, other: &Hand) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Hand {
+    fn cmp(&self, other: &Hand) -> Ordering {
+        let score1: usize = self.score();
+        let score2 = other.score();
+
+        if score1 == score2 {
+            self.cards.cmp(&other.cards)
+        } else {
+            score2.cmp(&score1)
+        }
+    }
+}
+
+#[derive(PartialEq, Eq, Clone)]
+struct Hand2 {
+    cards: Vec<Card2>,
+    bid: usize,
+    jokers: usize,
+}
+
+impl Hand2 {
+    fn of_a_kind(&self) -> Vec<usize> {
+        let mut counters = BTreeMap::<Card2, usize>::new();
+        for card in self.cards.iter() {
+            if *card != Card2::J {
+                *(counters.entry(*card).or_default()) += 1;
+            }
+        }
+        let mut result: Vec<usize> = counters.values().copied().collect();
+        result.sort_by(|a,b| b.cmp(a));
+        result.push(0);
+        result.push(0);
+        result
+    }
+
+    fn score(&self) -> usize {
+        let of_a_kind = self.of_a_kind();
+        if of_a_kind[0] + self.jokers == 5 {
+            7
+        } else if of_a_kind[0] + self.jokers == 4 {
+            6
+        } else if of_a_kind[0] + self.jokers == 3 && of_a_kind[1] == 2  {
+            5
+        } else if of_a_kind[0] + self.jokers == 3 {
+            4
+        } else if of_a_kind[0] == 2 && of_a_kind[1] == 2 {
+            3
+        } else if of_a_kind[0] + self.jokers == 2 {
+            2
+        } else {
+            1
+        }
+    }
+}
+
+impl PartialOrd for Hand2 {
+    fn partial_cmp(&self, other: &Hand2) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Hand2 {
+    fn cmp(&self, other: &Hand2) -> Ordering {
+        let score1: usize = self.score();
+        let score2 = other.score();
+
+        if score1 == score2 {
+            self.cards.cmp(&other.cards)
+        } else {
+            score2.cmp(&score1)
+        }
+    }
+}
+
+pub fn parse(input_str: &str) -> Result<Hands, ParseError> {
+    let source = Source::new(input_str);
+
+    let hands = input_str.lines().filter(|l| !l.is_empty()).map(|l| {
+        let (cards_str, rank_str) = source.split_once(l, " ")?;
+        if cards_str.chars().count() != 5 {
+            return Err(source.error(cards_str, "a hand of five cards"));
+        }
+        Ok(Hand { cards: parse_hand(&source, cards_str)?, bid: source.number(rank_str)? })
+    }).collect::<Result<Vec<Hand>, ParseError>>()?;
+
+    let hands2 = input_str.lines().filter(|l| !l.is_empty()).map(|l| {
+        let (cards_str, rank_str) = source.split_once(l, " ")?;
+        let cards = parse_hand2(&source, cards_str)?;
+        Ok(Hand2 { bid: source.number(rank_str)?, jokers: cards.iter().filter(|c| **c == Card2::J).count(), cards })
+    }).collect::<Result<Vec<Hand2>, ParseError>>()?;
+
+    Ok(Hands { hands, hands2 })
+}
+
+// The sum of the bids times their ranks, fails when that doesn't fit in 64 bits
+fn winnings(bids: impl Iterator<Item = usize>) -> Result<Answer, String> {
+    let result = bids.enumerate().try_fold(0usize, |acc, (i, bid)| acc.checked_add((i+1).checked_mul(bid)?));
+
+    Ok(result.ok_or("the winnings don't fit in 64 bits")?.into())
+}
+
+pub fn part1(hands: &Hands) -> Result<Answer, String> {
+    let mut input = hands.hands.clone();
+    input.sort();
+
+    winnings(input.iter().rev().map(|hand| hand.bid))
+}
+
+pub fn part2(hands: &Hands) -> Result<Answer, String> {
+    let mut input2 = hands.hands2.clone();
+    input2.sort();
+
+    winnings(input2.iter().rev().map(|hand| hand.bid))
+}
+
+/// `size` hands of five cards with bids up to 1000
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    const CARDS: &[u8] = b"AKQJT98765432";
+
+    let mut out = String::new();
+    for _ in 0..size {
+        // Drawing from a few kinds gives pairs and full houses more often
+        let kinds: Vec<u8> = (0..rng.range(1..=5)).map(|_| *rng.pick(CARDS)).collect();
+        let hand: String = (0..5).map(|_| *rng.pick(&kinds) as char).collect();
+        out += &format!("{} {}\n", hand, rng.range(1..=1000));
+    }
+    out
+}
@@ -0,0 +1,208 @@
+use std::collections::{BTreeMap, BTreeSet};
+
+use aoc_common::{cycle, Answer, Params, ParseError, Rng, Solution, Source};
+
+pub struct Day08;
+
+impl Solution for Day08 {
+    type Input = Network;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse(input)
+    }
+
+    fn part1(input: &Self::Input, _params: &Params) -> Result<Answer, String> {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input, _params: &Params) -> Result<Answer, String> {
+        part2(input)
+    }
+
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        generate(rng, size)
+    }
+}
+
+pub struct Network {
+    instructions: Vec<u8>,
+    mapping: BTreeMap<String, (String, String)>,
+}
+
+pub fn parse(input_str: &str) -> Result<Network, ParseError> {
+    let source = Source::new(input_str);
+    let (instructions_str, network) = source.split_once(input_str, "\n\n")?;
+
+    if let Some(i) = instructions_str.find(|c| c != 'L' && c != 'R') {
+        return Err(source.error(&instructions_str[i..], "`L` or `R`"));
+    }
+    if instructions_str.is_empty() {
+        return Err(source.error(instructions_str, "`L` or `R`"));
+    }
+    let instructions = instructions_str.as_bytes().to_vec();
+
+    let nodes = network
+        .lines()
+        .filter(|l| !l.is_empty())
+        .map(|line| {
+            let (key, value) = source.split_once(line, " = ")?;
+            let value = source.strip_prefix(value, "(")?;
+            let value = source.strip_suffix(value, ")")?;
+            let values = source.split_once(value, ", ")?;
+            Ok((key, values))
+        })
+        .collect::<Result<Vec<_>, ParseError>>()?;
+    let mapping: BTreeMap<String, (String, String)> = nodes.iter().map(|(key, values)| (key.to_string(), (values.0.to_owned(), values.1.to_owned()))).collect();
+
+    // The ghosts can't walk off the network
+    if let Some(next) = nodes.iter().flat_map(|(_, (left, right))| [left, right]).find(|next| !mapping.contains_key(**next)) {
+        return Err(source.error(next, "a node of the network"));
+    }
+
+    Ok(Network { instructions, mapping })
+}
+
+pub fn part1(input: &Network) -> Result<Answer, String> {
+    let Network { instructions, mapping } = input;
+
+    let mut cur = "AAA".to_string();
+    if !mapping.contains_key(&cur) {
+        return Err("there is no node AAA".to_string());
+    }
+
+    let mut i = 0;
+
+    while cur != "ZZZ" {
+        // Past every node and position in the instructions it walks in circles
+        if i > mapping.len() * instructions.len() {
+            return Err("AAA never leads to ZZZ".to_string());
+        }
+        let (left, right) = &mapping[&cur];
+        cur = if instructions[i% instructions.len()] == b'R' {
+            right.to_owned()
+        } else {
+            left.to_owned()
+        };
+        i += 1;
+    }
+
+    Ok(i.into())
+}
+
+pub fn part2(input: &Network) -> Result<Answer, String> {
+    let Network { instructions, mapping } = input;
+
+    // A ghost is at a node and about to follow an instruction, with finitely many of those
+    // it ends up walking in circles
+    let step = |&(node, i): &(&str, usize)| {
+        let (left, right) = &mapping[node];
+        let next = if instructions[i] == b'R' { right } else { left };
+        (next.as_str(), (i + 1) % instructions.len())
+    };
+
+    let init: Vec<(&str, usize)> = mapping.keys().filter(|k| k.ends_with('A')).map(|k| (k.as_str(), 0)).collect();
+
+    // The steps after which every ghost is in its circle, and for each ghost the steps in
+    // its circle that end on a Z, as congruences modulo the length of the circle
+    let mut settled = 0;
+    let mut z_steps = Vec::new();
+    for &ghost in &init {
+        let cycle = cycle::brent(&ghost, step);
+        let mut cur = ghost;
+        let mut steps = Vec::new();
+        for i in 0..cycle.start + cycle.length {
+            if i >= cycle.start && cur.0.ends_with('Z') {
+                steps.push((i as i128, cycle.length as i128));
+            }
+            cur = step(&cur);
+        }
+        settled = settled.max(cycle.start);
+        z_steps.push(steps);
+    }
+
+    // Until then they could meet without going in circles
+    let mut ghosts = init;
+    for i in 0..settled {
+        if ghosts.iter().all(|g| g.0.ends_with('Z')) {
+            return Ok(i.into());
+        }
+        ghosts = ghosts.iter().map(step).collect();
+    }
+
+    // Every combination of Z steps of the ghosts
+    let mut combined = vec![(0, 1)];
+    for steps in z_steps {
+        combined = combined.iter().flat_map(|&c| steps.iter().filter_map(move |&s| cycle::crt([c, s]))).collect();
+    }
+    let settled = settled as u64;
+    combined
+        .into_iter()
+        .filter_map(|(first, period)| Some((u64::try_from(first).ok()?, u64::try_from(period).ok()?)))
+        .filter_map(|(first, period)| settled.saturating_sub(first).div_ceil(period).checked_mul(period)?.checked_add(first))
+        .min()
+        .map(Answer::from)
+        .ok_or_else(|| "the ghosts never all stand on a Z".to_string())
+}
+
+/// `size` instructions and six ghosts, the first one starting at AAA. Each ghost walks a
+/// ring of a small prime times `size` nodes that ends in its Z node, the branches it never
+/// takes lead anywhere.
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    const PRIMES: [usize; 10] = [3, 5, 7, 11, 13, 17, 19, 23, 29, 31];
+    const LETTERS: &[u8] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZ";
+    // No A or Z at the end of the nodes in between
+    const MIDDLE: &[u8] = b"BCDEFGHIJKLMNOPQRSTUVWXY";
+
+    let len = size.max(1);
+    let instructions: Vec<u8> = (0..len).map(|_| *rng.pick(b"LR")).collect();
+    let mut primes = PRIMES;
+    rng.shuffle(&mut primes);
+    let rings: Vec<usize> = primes[..6].iter().map(|p| p * len).collect();
+
+    // Longer names in between once the three letter ones run out, AAA and ZZZ stay
+    let count: usize = rings.iter().sum();
+    let width = (3..).find(|&w| 26usize.pow(w as u32 - 1) * MIDDLE.len() >= 2 * count).unwrap();
+    let name = |rng: &mut Rng, width: usize, last: &[u8]| -> String {
+        let mut name: Vec<u8> = (0..width - 1).map(|_| *rng.pick(LETTERS)).collect();
+        name.push(*rng.pick(last));
+        String::from_utf8(name).unwrap()
+    };
+
+    let mut taken = BTreeSet::new();
+    let mut ghosts: Vec<(String, Vec<String>)> = Vec::new();
+    for (ghost, &ring) in rings.iter().enumerate() {
+        let prefix = loop {
+            let prefix = if ghost == 0 { "AA".to_string() } else { name(rng, 3, LETTERS)[1..].to_string() };
+            // ZZZ belongs to the first ghost
+            if (ghost == 0 || prefix != "ZZ") && taken.insert(prefix.clone() + "A") {
+                break prefix;
+            }
+        };
+        let mut nodes: Vec<String> = Vec::new();
+        while nodes.len() < ring - 1 {
+            let node = name(rng, width, MIDDLE);
+            if taken.insert(node.clone()) {
+                nodes.push(node);
+            }
+        }
+        nodes.push(if ghost == 0 { "ZZZ".to_string() } else { prefix.clone() + "Z" });
+        ghosts.push((prefix + "A", nodes));
+    }
+    let all: Vec<&String> = ghosts.iter().flat_map(|(_, nodes)| nodes).collect();
+
+    let mut lines = Vec::new();
+    for (start, nodes) in ghosts.iter() {
+        // The node reached after `i` steps follows instruction `i`, the Z node leads back
+        // to where the start led
+        let steps = std::iter::once(start).chain(nodes.iter()).enumerate();
+        for (i, node) in steps {
+            let next = &nodes[i % nodes.len()];
+            let decoy = *rng.pick(&all);
+            let (left, right) = if instructions[i % len] == b'L' { (next, decoy) } else { (decoy, next) };
+            lines.push(format!("{} = ({}, {})\n", node, left, right));
+        }
+    }
+    rng.shuffle(&mut lines);
+
+    String::from_utf8(instructions).unwrap() + "\n\n" + &lines.concat()
+}
@@ -0,0 +1,112 @@
+use aoc_common::{Answer, Params, ParseError, Rng, Solution, Source};
+
+pub struct Day09;
+
+impl Solution for Day09 {
+    type Input = Vec<Vec<i64>>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse(input)
+    }
+
+    fn part1(input: &Self::Input, _params: &Params) -> Result<Answer, String> {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input, _params: &Params) -> Result<Answer, String> {
+        part2(input)
+    }
+
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        generate(rng, size)
+    }
+}
+
+// None when a difference doesn't fit in 64 bits
+fn calc_diffs(values: &[i64]) -> Option<Vec<i64>> {
+    values.windows(2).map(|w| w[1].checked_sub(w[0])).collect()
+}
+
+// The last row is empty when the differences only reach zero by running out of values
+fn calc_all_diffs(values: &[i64]) -> Option<Vec<Vec<i64>>> {
+    let mut all_diffs: Vec<Vec<i64>> = Vec::new();
+    let mut diffs = calc_diffs(values)?;
+    while diffs.iter().any(|&x| x != 0) {
+        let new_diffs = calc_diffs(&diffs)?;
+        all_diffs.push(diffs);
+        diffs = new_diffs;
+    }
+    all_diffs.push(diffs);
+    Some(all_diffs)
+}
+
+fn predict_next(values: &[i64]) -> Option<i64> {
+    let diffs = calc_all_diffs(values)?;
+    let mut next_cur_diff: i64 = 0;
+    for d in diffs.iter().rev() {
+        next_cur_diff = next_cur_diff.checked_add(d.last().copied().unwrap_or(0))?;
+    }
+    next_cur_diff.checked_add(*values.last()?)
+}
+
+fn predict_prev(values: &[i64]) -> Option<i64> {
+    let diffs = calc_all_diffs(values)?;
+    let mut next_cur_diff: i64 = 0;
+    for d in diffs.iter().rev() {
+        next_cur_diff = d.first().copied().unwrap_or(0).checked_sub(next_cur_diff)?;
+    }
+    values.first()?.checked_sub(next_cur_diff)
+}
+
+// Sums the predictions, failing for the first history that doesn't fit in 64 bits
+fn sum(values: &[Vec<i64>], predict: fn(&[i64]) -> Option<i64>) -> Result<Answer, String> {
+    let mut result: i64 = 0;
+    for (i, v) in values.iter().enumerate() {
+        result = predict(v).and_then(|p| result.checked_add(p))
+            .ok_or_else(|| format!("history {} doesn't extrapolate within 64 bits", i + 1))?;
+    }
+    Ok(result.into())
+}
+
+pub fn parse(input_str: &str) -> Result<Vec<Vec<i64>>, ParseError> {
+    let source = Source::new(input_str);
+    input_str
+        .lines()
+        .filter(|l| !l.is_empty())
+        .map(|l| {
+            let values: Vec<i64> = l.split_whitespace().map(|n| source.number::<i64>(n)).collect::<Result<_, _>>()?;
+            if values.is_empty() {
+                return Err(source.error(l, "a number"));
+            }
+            Ok(values)
+        })
+        .collect()
+}
+
+pub fn part1(values: &[Vec<i64>]) -> Result<Answer, String> {
+    sum(values, predict_next)
+}
+
+pub fn part2(values: &[Vec<i64>]) -> Result<Answer, String> {
+    sum(values, predict_prev)
+}
+
+/// `size` histories of 21 values each, polynomials of a degree up to 6 so the
+/// differences reach zero in time
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let mut out = String::new();
+    for _ in 0..size {
+        // The first value of every row of differences, summing them up walks the history
+        let mut diffs: Vec<i64> = (0..=rng.range(0..=6)).map(|_| rng.range(-10..=10)).collect();
+        let mut values = Vec::new();
+        for _ in 0..21 {
+            values.push(diffs[0].to_string());
+            for k in 0..diffs.len() - 1 {
+                diffs[k] += diffs[k + 1];
+            }
+        }
+        out += &values.join(" ");
+        out.push('\n');
+    }
+    out
+}
@@ -0,0 +1,201 @@
+use aoc_common::{Answer, Direction, Grid, Params, ParseError, Pos, Rgb, Rng, Scene, Solution, Source};
+
+pub struct Day10;
+
+impl Solution for Day10 {
+    type Input = Grid<char>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse(input)
+    }
+
+    fn part1(input: &Self::Input, _params: &Params) -> Result<Answer, String> {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input, _params: &Params) -> Result<Answer, String> {
+        part2(input)
+    }
+
+    fn render(input: &Self::Input, _params: &Params, _part: u8) -> Option<Scene> {
+        render(input)
+    }
+
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        generate(rng, size)
+    }
+}
+
+const NW: char = 'J';
+const NE: char = 'L';
+const SW: char = '7';
+const SE: char = 'F';
+
+// The two directions a pipe connects
+fn connections(c: char) -> Option<[Direction; 2]> {
+    use Direction::*;
+    match c {
+        '|' => Some([Up, Down]),
+        '-' => Some([Left, Right]),
+        NW => Some([Up, Left]),
+        NE => Some([Up, Right]),
+        SW => Some([Down, Left]),
+        SE => Some([Down, Right]),
+        _ => None,
+    }
+}
+
+// None when the pipe leads off the grid or into a tile that doesn't connect back
+fn find_next(input: &Grid<char>, path: &[Pos]) -> Option<Pos> {
+    let pos = path[path.len() - 1];
+    let previous = path[path.len() - 2];
+    let (d, next) = connections(input[pos])?
+        .into_iter()
+        .map(|d| (d, input.step(pos, d)))
+        .find(|&(_, next)| next != Some(previous))?;
+    let next = next?;
+    (input[next] == 'S' || connections(input[next])?.contains(&d.opposite())).then_some(next)
+}
+
+pub fn parse(input_str: &str) -> Result<Grid<char>, ParseError> {
+    let source = Source::new(input_str);
+    let lines = source.grid(input_str, |c| "|-LJ7F.S".contains(c), "a pipe, `.` or `S`")?;
+    if !input_str.contains('S') {
+        return Err(source.error_after(input_str, "a start tile `S`"));
+    }
+    if let Some(i) = input_str.match_indices('S').nth(1).map(|(i, _)| i) {
+        return Err(source.error(&input_str[i..i + 1], "a single start tile"));
+    }
+    Ok(Grid::from_lines(&lines, |c| c))
+}
+
+fn find_loop(input: &Grid<char>) -> Result<Vec<Pos>, String> {
+    let start = input.position(|&c| c == 'S').ok_or("there is no start tile")?;
+
+    let mut path: Vec<Pos> = Vec::new();
+
+    path.push(start);
+
+    // the first pipe around the start that connects back to it
+    let first = Direction::ALL
+        .into_iter()
+        .find_map(|d| {
+            let next = input.step(start, d)?;
+            connections(input[next])?.contains(&d.opposite()).then_some(next)
+        })
+        .ok_or("no pipe connects to the start tile")?;
+    path.push(first);
+
+    loop {
+        let next = find_next(input, &path).ok_or_else(|| format!("the loop breaks off after {} pipes", path.len() - 1))?;
+        if input[next] == 'S' {
+            break;
+        }
+        path.push(next);
+    }
+
+    Ok(path)
+}
+
+pub fn part1(input: &Grid<char>) -> Result<Answer, String> {
+    let path = find_loop(input)?;
+
+    Ok((path.len() / 2).into())
+}
+
+// The tiles enclosed by the loop
+fn inside_tiles(input: &Grid<char>, path: &[Pos]) -> Vec<Pos> {
+    let start = path[0];
+
+    let mut loop_positions = Grid::new(input.width(), input.height(), '.');
+
+    for &p in path.iter() {
+        loop_positions[p] = input[p];
+    }
+
+    // replace S by appropriate part, based on where the loop leaves and enters it
+    // (looking at the neighbouring loop tiles is not enough, they might belong to
+    // a different stretch of the loop)
+    let neighbours = [path[1], path[path.len() - 1]];
+    let up = neighbours.iter().any(|n| n.row < start.row);
+    let down = neighbours.iter().any(|n| n.row > start.row);
+    let left = neighbours.iter().any(|n| n.col < start.col);
+    let right = neighbours.iter().any(|n| n.col > start.col);
+    loop_positions[start] = match (up, down, left, right) {
+        (true, true, _, _) => '|',
+        (_, _, true, true) => '-',
+        (true, _, true, _) => NW,
+        (true, _, _, true) => NE,
+        (_, true, true, _) => SW,
+        (_, true, _, true) => SE,
+        _ => unreachable!("the loop leaves and enters the start from two sides"),
+    };
+
+    let mut inside = Vec::new();
+
+    for (y, row) in loop_positions.rows().enumerate() {
+        for (x, &c) in row.iter().enumerate() {
+            if c == '.' {
+                let is_crossing = |c: &&char| **c == '|' || **c == NE || **c == NW;
+                let left_count = row[..x].iter().filter(is_crossing).count();
+                let right_count = row[x+1..].iter().filter(is_crossing).count();
+                if left_count % 2 == 1 && right_count % 2 == 1 {
+                    inside.push(Pos::new(y, x));
+                }
+            }
+        }
+    }
+
+    inside
+}
+
+pub fn part2(input: &Grid<char>) -> Result<Answer, String> {
+    let path = find_loop(input)?;
+    Ok(inside_tiles(input, &path).len().into())
+}
+
+// The loop with the tiles inside it in green, for both parts
+fn render(input: &Grid<char>) -> Option<Scene> {
+    let path = find_loop(input).ok()?;
+    let mut cells = input.map(|&c| if c == '.' { Rgb::WHITE } else { Rgb::gray(210) });
+    for &pos in path.iter() {
+        cells[pos] = Rgb::gray(120);
+    }
+    for pos in inside_tiles(input, &path) {
+        cells[pos] = Rgb::GREEN;
+    }
+    Some(Scene::grid(cells).polygon(path.iter().map(|&p| Scene::center(p)), Rgb::BLACK, None))
+}
+
+/// A `size` by `size` field with a single loop and junk pipes everywhere else. Only the
+/// two loop pipes next to `S` connect to it.
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let size = size.max(3);
+    let path = rng.closed_loop(size);
+    let mut field = Grid::new(size, size, '.');
+    for pos in field.positions() {
+        field[pos] = *rng.pick(&['|', '-', NW, NE, SW, SE, '.', '.']);
+    }
+    let direction = |from: Pos, to: Pos| Direction::ALL.into_iter().find(|&d| field.step(from, d) == Some(to)).unwrap();
+    let mut pipes = Vec::new();
+    for (i, &pos) in path.iter().enumerate() {
+        let ends = [direction(pos, path[(i + path.len() - 1) % path.len()]), direction(pos, path[(i + 1) % path.len()])];
+        let pipe = ['|', '-', NW, NE, SW, SE].into_iter().find(|&c| ends.iter().all(|d| connections(c).unwrap().contains(d)));
+        pipes.push((pos, pipe.unwrap()));
+    }
+    for (pos, pipe) in pipes {
+        field[pos] = pipe;
+    }
+
+    let start = *rng.pick(&path);
+    field[start] = 'S';
+    for direction in Direction::ALL {
+        if let Some(next) = field.step(start, direction).filter(|p| !path.contains(p)) {
+            if connections(field[next]).is_some_and(|c| c.contains(&direction.opposite())) {
+                field[next] = '.';
+            }
+        }
+    }
+
+    field.to_string()
+}
@@ -0,0 +1,145 @@
+use std::{cmp::{max, min}, collections::BTreeSet};
+
+use aoc_common::{Answer, Grid, Param, Params, ParseError, Pos, Rng, Solution, Source};
+
+pub struct Day11;
+
+impl Solution for Day11 {
+    type Input = Grid<char>;
+
+    const PARAMS: &'static [Param] = &[FACTOR];
+
+    fn check_params(params: &Params) -> Result<(), String> {
+        params.get_usize(&FACTOR).map(|_| ())
+    }
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse(input)
+    }
+
+    fn part1(input: &Self::Input, _params: &Params) -> Result<Answer, String> {
+        Ok(part1(input))
+    }
+
+    fn part2(input: &Self::Input, params: &Params) -> Result<Answer, String> {
+        Ok(part2(input, params.get_usize(&FACTOR)?))
+    }
+
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        generate(rng, size)
+    }
+}
+
+const FACTOR: Param = Param { name: "factor", default: 1_000_000, help: "how many times larger empty space gets (part 2)" };
+
+pub fn parse(input_str: &str) -> Result<Grid<char>, ParseError> {
+    let source = Source::new(input_str);
+    let lines = source.grid(input_str, |c| c == '.' || c == '#', "`.` or `#`")?;
+    Ok(Grid::from_lines(&lines, |c| c))
+}
+
+fn find_empty(input: &Grid<char>) -> (BTreeSet<usize>, BTreeSet<usize>) {
+    let mut empty_rows = BTreeSet::new();
+
+    for (i, row) in input.rows().enumerate() {
+        if row.iter().all(|&c| c == '.') {
+            empty_rows.insert(i);
+        }
+    }
+
+    let mut empty_cols = BTreeSet::new();
+    for i in 0..input.width() {
+        if input.column(i).all(|&c| c == '.') {
+            empty_cols.insert(i);
+        }
+    }
+
+    (empty_rows, empty_cols)
+}
+
+fn galaxies(input: &Grid<char>) -> Vec<Pos> {
+    input.iter().filter(|(_, &c)| c == '#').map(|(p, _)| p).collect()
+}
+
+pub fn part1(input: &Grid<char>) -> Answer {
+    let (empty_rows, empty_cols) = find_empty(input);
+
+    let mut new_input = vec![];
+
+    for (i, row) in input.rows().enumerate() {
+        if empty_rows.contains(&i) {
+            new_input.push(vec!['.'; row.len() + empty_cols.len()]);
+        }
+
+        let mut new_row = vec![];
+        for (j, &c) in row.iter().enumerate() {
+            if empty_cols.contains(&j) {
+                new_row.push('.');
+            }
+            new_row.push(c);
+        }
+        new_input.push(new_row);
+    }
+
+    let new_input = Grid::from_rows(new_input);
+    let galaxy_positions = galaxies(&new_input);
+
+    let mut galaxy_distances = 0;
+
+    for (i, pos) in galaxy_positions.iter().enumerate() {
+        for other_pos in galaxy_positions.iter().skip(i + 1) {
+            galaxy_distances += pos.manhattan(*other_pos);
+        }
+    }
+
+    galaxy_distances.into()
+}
+
+pub fn part2(input: &Grid<char>, factor: usize) -> Answer {
+    let (empty_rows, empty_cols) = find_empty(input);
+
+    let galaxy_positions = galaxies(input);
+
+    let mut galaxy_distances = 0;
+
+    // Not super fast but ok
+
+    for (i, pos) in galaxy_positions.iter().enumerate() {
+        for other_pos in galaxy_positions.iter().skip(i + 1) {
+            let (y1, x1) = (&pos.row, &pos.col);
+            let (y2, x2) = (&other_pos.row, &other_pos.col);
+            let mut dist = 0;
+            for y in min(y1+1, y2+1)..=max(*y1, *y2) {
+                if empty_rows.contains(&y) {
+                    dist += factor;
+                } else {
+                    dist += 1;
+                }
+            }
+            for x in min(x1+1, x2+1)..=*max(x1, x2) {
+                if empty_cols.contains(&x) {
+                    dist += factor;
+                } else {
+                    dist += 1;
+                }
+            }
+            galaxy_distances += dist;
+        }
+    }
+
+    galaxy_distances.into()
+}
+
+/// A `size` by `size` image with about one galaxy in twenty cells, and some rows and
+/// columns left empty to expand
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let empty_rows: Vec<bool> = (0..size).map(|_| rng.chance(0.1)).collect();
+    let empty_cols: Vec<bool> = (0..size).map(|_| rng.chance(0.1)).collect();
+    let mut image = Grid::new(size, size, '.');
+    for pos in image.positions() {
+        if !empty_rows[pos.row] && !empty_cols[pos.col] && rng.chance(0.05) {
+            image[pos] = '#';
+        }
+    }
+    image.to_string()
+}
@@ -1,14 +1,38 @@
-use std::{fs::read_to_string, collections::BTreeMap};
+use std::collections::BTreeMap;
 
 use itertools::Itertools;
 
-struct Line {
+use aoc_common::{Answer, Params, ParseError, Rng, Solution, Source};
+
+pub struct Day12;
+
+impl Solution for Day12 {
+    type Input = Vec<Line>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse(input)
+    }
+
+    fn part1(input: &Self::Input, _params: &Params) -> Result<Answer, String> {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input, _params: &Params) -> Result<Answer, String> {
+        part2(input)
+    }
+
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        generate(rng, size)
+    }
+}
+
+pub struct Line {
     positions: Vec<char>,
     numbers: Vec<usize>,
 }
 
 impl Line {
-    fn check(&self, line: &Vec<char>) -> bool {
+    fn check(&self, line: &[char]) -> bool {
         let mut line_iter = line.iter();
         for i in self.numbers.iter() {
             while line_iter.next() == Some(&'.') {}
@@ -27,11 +51,18 @@ impl Line {
         let mut count = 0;
         let base = self.positions.clone();
         let num_hash = self.positions.iter().filter(|x| **x == '#').count();
-        if num_hash > self.numbers.iter().sum::<usize>() {
+        // More springs than fit in the line can't be arranged, not even when they overflow
+        let Some(sum) = self.numbers.iter().try_fold(0usize, |sum, n| sum.checked_add(*n)) else {
+            return 0;
+        };
+        if num_hash > sum {
             return 0;
         }
-        let sum_set = self.numbers.iter().sum::<usize>() - num_hash;
+        let sum_set = sum - num_hash;
         let quest_positions: Vec<usize> = self.positions.iter().enumerate().filter(|(_, x)| **x == '?').map(|(i, _)| i).collect();
+        if sum_set > quest_positions.len() {
+            return 0;
+        }
         // we need to set sum_set '?' positions to #, the others to . 
         for i in (0..quest_positions.len()).combinations(sum_set) {
             let mut new_line = base.clone();
@@ -46,9 +77,10 @@ impl Line {
         count
     }
 
-    fn count_possibilities2(&self) -> usize {
+    // None when the count doesn't fit in 64 bits
+    fn count_possibilities2(&self) -> Option<usize> {
         // It's DP. It's not beautiful, it could probably be faster, but it works.
-        let mut dp_table = BTreeMap::new();
+        let mut dp_table: BTreeMap<(usize, usize), usize> = BTreeMap::new();
         dp_table.insert((self.positions.len(), self.numbers.len()), 1);
         dp_table.insert((self.positions.len()+1, self.numbers.len()), 1);
         for i in (0..self.positions.len()).rev() {
@@ -80,7 +112,7 @@ impl Line {
                     new_line_i += *number;
                     if dp_table.contains_key(&(i + new_line_i + 1, j + 1)) && self.positions.get(i + new_line_i).unwrap_or(&'?') != &'#' {
                         let new_n = if self.positions[i] == '?' {
-                            dp_table.get(&(i + new_line_i + 1, j + 1)).unwrap() + dp_table.get(&(i + 1, j)).unwrap_or(&0)
+                            dp_table.get(&(i + new_line_i + 1, j + 1)).unwrap().checked_add(*dp_table.get(&(i + 1, j)).unwrap_or(&0))?
                         } else {
                             *dp_table.get(&(i + new_line_i + 1, j + 1)).unwrap()
                         };
@@ -95,7 +127,7 @@ impl Line {
                 }
             }
         }
-        *dp_table.get(&(0, 0)).unwrap_or(&0)
+        Some(*dp_table.get(&(0, 0)).unwrap_or(&0))
     }
 
     fn unfold(&self) -> Line {
@@ -112,27 +144,89 @@ impl Line {
     }
 }
 
-fn main() {
-    let input_str = read_to_string("input.txt").unwrap();
-    
-    let input: Vec<Line> = input_str
+pub fn parse(input_str: &str) -> Result<Vec<Line>, ParseError> {
+    let source = Source::new(input_str);
+    input_str
         .split("\n")
         .filter(|x| !x.is_empty())
         .map(|x| {
-            let (positions_str, numbers_str) = x.split_once(" ").unwrap();
+            let (positions_str, numbers_str) = source.split_once(x, " ")?;
+            if let Some(i) = positions_str.find(|c| !".#?".contains(c)) {
+                return Err(source.error(&positions_str[i..], "`.`, `#` or `?`"));
+            }
             let positions = positions_str.chars().collect();
-            let numbers = numbers_str.split(",").map(|x| x.parse::<usize>().unwrap()).collect();
-            Line { positions, numbers }
+            let numbers = numbers_str.split(",").map(|x| source.number::<usize>(x)).collect::<Result<_, _>>()?;
+            Ok(Line { positions, numbers })
         })
-        .collect();
+        .collect()
+}
 
-    let result = input.iter().map(|x| x.count_possibilities()).sum::<usize>();
+pub fn part1(input: &[Line]) -> Result<Answer, String> {
+    let result = input.iter().try_fold(0usize, |sum, x| sum.checked_add(x.count_possibilities()))
+        .ok_or("the arrangements don't fit in 64 bits")?;
 
-    println!("result 1: {}", result);
+    Ok(result.into())
+}
 
+pub fn part2(input: &[Line]) -> Result<Answer, String> {
     let input2 = input.iter().map(|x| x.unfold()).collect::<Vec<Line>>();
 
-    let result2 = input2.iter().map(|x| x.count_possibilities2()).sum::<usize>();
+    let result2 = input2.iter().try_fold(0usize, |sum, x| sum.checked_add(x.count_possibilities2()?))
+        .ok_or("the arrangements don't fit in 64 bits")?;
 
-    println!("result 2: {}", result2);
+    Ok(result2.into())
+}
+
+/// `size` rows of up to 20 springs, made from a real arrangement with about half of the
+/// springs hidden behind `?`
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let mut out = String::new();
+    for _ in 0..size {
+        let mut groups = Vec::new();
+        let mut row = ".".repeat(rng.range(0..=2) as usize);
+        for _ in 0..rng.range(1..=6) {
+            let group = rng.range(1..=5) as usize;
+            // A group and the gap after it still have to fit
+            if row.len() + group + 1 > 20 {
+                break;
+            }
+            row += &"#".repeat(group);
+            row += &".".repeat(rng.range(1..=3) as usize);
+            groups.push(group.to_string());
+        }
+        if groups.is_empty() {
+            row = "#.".to_string();
+            groups.push("1".to_string());
+        }
+        row.truncate(20);
+
+        let hidden: String = row.chars().map(|c| if rng.chance(0.5) { '?' } else { c }).collect();
+        out += &format!("{} {}\n", hidden, groups.join(","));
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proptest::prelude::*;
+
+    fn line() -> impl Strategy<Value = Line> {
+        let positions = proptest::collection::vec(prop_oneof![Just('.'), Just('#'), Just('?')], 1..=14);
+        let numbers = proptest::collection::vec(1..=4usize, 1..=4);
+        (positions, numbers).prop_map(|(positions, numbers)| Line { positions, numbers })
+    }
+
+    impl std::fmt::Debug for Line {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            write!(f, "{} {}", self.positions.iter().collect::<String>(), self.numbers.iter().join(","))
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn dp_matches_combinations(line in line()) {
+            prop_assert_eq!(line.count_possibilities2(), Some(line.count_possibilities()));
+        }
+    }
 }
@@ -0,0 +1,102 @@
+// I know it's not very readable but i wanted to practice my iterators
+
+use aoc_common::{Answer, Grid, Params, ParseError, Pos, Rng, Solution, Source};
+
+pub struct Day13;
+
+impl Solution for Day13 {
+    type Input = Vec<Grid<char>>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse(input)
+    }
+
+    fn part1(input: &Self::Input, _params: &Params) -> Result<Answer, String> {
+        Ok(part1(input))
+    }
+
+    fn part2(input: &Self::Input, _params: &Params) -> Result<Answer, String> {
+        Ok(part2(input))
+    }
+
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        generate(rng, size)
+    }
+}
+
+fn diff_rows(field: &Grid<char>, line: usize) -> usize {
+    field.rows()
+        .skip(line)
+        .zip(field.rows().rev().skip(field.height() - line))
+        .map(|(a, b)| a.iter().zip(b.iter()).filter(|(a, b)| a != b).count())
+        .sum()
+}
+
+fn find_line(field: &Grid<char>, diff: usize) -> Option<usize> {
+    (1..field.height()).find(|&i| diff_rows(field, i) == diff)
+}
+
+pub fn parse(input_str: &str) -> Result<Vec<Grid<char>>, ParseError> {
+    let source = Source::new(input_str);
+    input_str
+        .split("\n\n")
+        .filter(|g| !g.is_empty())
+        .map(|g| {
+            let lines = source.grid(g, |c| c == '.' || c == '#', "`.` or `#`")?;
+            Ok(Grid::from_lines(&lines, |c| c))
+        })
+        .collect()
+}
+
+pub fn part1(input: &[Grid<char>]) -> Answer {
+    let mut result = 0;
+
+    for field in input {
+        result += 100 * find_line(field, 0).unwrap_or(0);
+        // the columns of a field are the rows of its transposition
+        result += find_line(&field.transpose(), 0).unwrap_or(0);
+    }
+
+    result.into()
+}
+
+pub fn part2(input: &[Grid<char>]) -> Answer {
+    let mut result = 0;
+
+    for field in input {
+        result += 100 * find_line(field, 1).unwrap_or(0);
+        result += find_line(&field.transpose(), 1).unwrap_or(0);
+    }
+
+    result.into()
+}
+
+/// `size` patterns, each with a perfect mirror for part 1 and one for part 2 that is off by
+/// a single smudge
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let mut patterns = Vec::new();
+    for _ in 0..size {
+        // Odd heights leave a row outside of the reflection in the rows
+        let (height, width) = (2 * rng.range(2..=8) as usize + 1, rng.range(5..=17) as usize);
+        let (row_line, col_line) = (rng.range(1..=height as i64 - 1) as usize, rng.range(1..=width as i64 - 1) as usize);
+        let mirror = |i: usize, line: usize, len: usize| (2 * line).checked_sub(i + 1).filter(|&m| m < len);
+
+        // Reflecting in both lines maps a cell to the same cell of `base`
+        let base = Grid::from_rows((0..height).map(|_| (0..width).map(|_| if rng.chance(0.5) { '#' } else { '.' }).collect::<Vec<_>>()));
+        let mut pattern = Grid::new(width, height, '.');
+        for pos in pattern.positions() {
+            let row = mirror(pos.row, row_line, height).map_or(pos.row, |m| m.min(pos.row));
+            let col = mirror(pos.col, col_line, width).map_or(pos.col, |m| m.min(pos.col));
+            pattern[pos] = base[Pos::new(row, col)];
+        }
+
+        // The smudge breaks the reflection in the columns but not the one in the rows
+        let rows: Vec<usize> = (0..height).filter(|&r| mirror(r, row_line, height).is_none()).collect();
+        let cols: Vec<usize> = (0..width).filter(|&c| mirror(c, col_line, width).is_some()).collect();
+        let smudge = Pos::new(*rng.pick(&rows), *rng.pick(&cols));
+        pattern[smudge] = if pattern[smudge] == '#' { '.' } else { '#' };
+
+        patterns.push(if rng.chance(0.5) { pattern.transpose() } else { pattern }.to_string());
+    }
+    patterns.join("\n")
+}
@@ -0,0 +1,135 @@
+use aoc_common::{cycle, Answer, Color, Frame, Grid, Param, Params, ParseError, Pos, Recording, Rng, Solution, Source};
+
+pub struct Day14;
+
+impl Solution for Day14 {
+    type Input = Grid<char>;
+
+    const PARAMS: &'static [Param] = &[CYCLES];
+
+    fn check_params(params: &Params) -> Result<(), String> {
+        params.get_usize(&CYCLES).map(|_| ())
+    }
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse(input)
+    }
+
+    fn part1(input: &Self::Input, _params: &Params) -> Result<Answer, String> {
+        Ok(part1(input))
+    }
+
+    fn part2(input: &Self::Input, params: &Params) -> Result<Answer, String> {
+        Ok(part2(input, params.get_usize(&CYCLES)?))
+    }
+
+    fn visualize(input: &Self::Input, _params: &Params, recording: &mut Recording) {
+        visualize(input, recording);
+    }
+
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        generate(rng, size)
+    }
+}
+
+const CYCLES: Param = Param { name: "cycles", default: 1_000_000_000, help: "spin cycles to run (part 2)" };
+
+// Rolls all round rocks as far up as they go
+fn rotate_north(field: &mut Grid<char>) {
+    for col in 0..field.width() {
+        let mut free = 0;
+        for row in 0..field.height() {
+            match field[Pos::new(row, col)] {
+                '#' => free = row + 1,
+                'O' => {
+                    field[Pos::new(row, col)] = '.';
+                    field[Pos::new(free, col)] = 'O';
+                    free += 1;
+                }
+                _ => {}
+            }
+        }
+    }
+}
+
+// North, west, south and east: turning the field clockwise after each tilt brings the
+// next side to the top, after four turns it is back in its original orientation
+fn spin_cycle(field: &mut Grid<char>) {
+    for _ in 0..4 {
+        rotate_north(field);
+        *field = field.rotate_cw();
+    }
+}
+
+fn north_load(field: &Grid<char>) -> usize {
+    field
+        .rows()
+        .enumerate()
+        .map(|(i, row)| row.iter().filter(|&&c| c == 'O').count() * (field.height() - i))
+        .sum()
+}
+
+// The tilts of the first spin cycles (as many as the puzzle text shows), the rocks that
+// moved are highlighted
+fn visualize(input: &Grid<char>, recording: &mut Recording) {
+    const SIDES: [&str; 4] = ["north", "west", "south", "east"];
+
+    let mut field = input.clone();
+    let mut shown = field.clone();
+    recording.push(|| Frame::grid(shown.clone()).line(format!("load on the north beams: {}", north_load(&shown))));
+    for cycle in 1..=3 {
+        for (turns, side) in SIDES.into_iter().enumerate() {
+            rotate_north(&mut field);
+            // Back into the original orientation
+            let tilted = (0..turns).fold(field.clone(), |f, _| f.rotate_ccw());
+            recording.push(|| {
+                let moved = tilted.iter().filter(|&(p, &c)| c == 'O' && shown[p] != 'O').map(|(p, _)| p);
+                Frame::grid(tilted.clone())
+                    .highlight_all(moved, Color::Yellow)
+                    .line(format!("cycle {}, tilted {}", cycle, side))
+                    .line(format!("load on the north beams: {}", north_load(&tilted)))
+            });
+            shown = tilted;
+            field = field.rotate_cw();
+        }
+    }
+}
+
+pub fn parse(input_str: &str) -> Result<Grid<char>, ParseError> {
+    let source = Source::new(input_str);
+    let lines = source.grid(input_str, |c| "O#.".contains(c), "`O`, `#` or `.`")?;
+    Ok(Grid::from_lines(&lines, |c| c))
+}
+
+pub fn part1(input: &Grid<char>) -> Answer {
+    let mut field = input.clone();
+
+    rotate_north(&mut field);
+
+    north_load(&field).into()
+}
+
+pub fn part2(input: &Grid<char>, cycles: usize) -> Answer {
+    let spin = |field: &Grid<char>| {
+        let mut field = field.clone();
+        spin_cycle(&mut field);
+        field
+    };
+    let field = cycle::nth(input, spin, cycles);
+
+    north_load(&field).into()
+}
+
+/// A `size` by `size` platform with round rocks on a fifth and cube rocks on a tenth of
+/// the cells
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let mut platform = Grid::new(size, size, '.');
+    for pos in platform.positions() {
+        platform[pos] = match rng.below(10) {
+            0 | 1 => 'O',
+            2 => '#',
+            _ => '.',
+        };
+    }
+    platform.to_string()
+}
@@ -0,0 +1,117 @@
+use aoc_common::{Answer, Params, ParseError, Rng, Solution, Source};
+
+pub struct Day15;
+
+impl Solution for Day15 {
+    type Input = Vec<String>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse(input)
+    }
+
+    fn part1(input: &Self::Input, _params: &Params) -> Result<Answer, String> {
+        Ok(part1(input))
+    }
+
+    fn part2(input: &Self::Input, _params: &Params) -> Result<Answer, String> {
+        Ok(part2(input))
+    }
+
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        generate(rng, size)
+    }
+}
+
+fn hash(str: &str) -> u8 {
+    let mut hash: u64 = 0;
+    for c in str.as_bytes() {
+        hash += *c as u64;
+        hash = (hash * 17) % 256;
+    }
+    hash as u8
+}
+
+#[derive(Clone, Debug)]
+struct Lens {
+    label: String,
+    focal_length: u32,
+}
+
+pub fn parse(input_str: &str) -> Result<Vec<String>, ParseError> {
+    let source = Source::new(input_str);
+    input_str.trim_end().split(",").map(|s| {
+        // Only part 2 looks at the operations, but bad ones should not get that far
+        if let Some((_, num)) = s.split_once("=") {
+            source.number::<u32>(num)?;
+        } else {
+            source.strip_suffix(s, "-")?;
+        }
+        Ok(s.to_string())
+    }).collect()
+}
+
+pub fn part1(steps: &[String]) -> Answer {
+    let result = steps.iter().map(|s| hash(s) as u64).sum::<u64>();
+
+    result.into()
+}
+
+pub fn part2(steps: &[String]) -> Answer {
+    let mut boxes: Vec<Vec<Lens>> = vec![vec![]; 256];
+
+    for step in steps {
+        if let Some((label, operation)) = step.split_once("=") {
+            let num = operation.parse::<u32>().unwrap();
+            let label_hash = hash(label);
+            let cur_box = &mut boxes[label_hash as usize];
+            if let Some((i, _)) = cur_box.iter().enumerate().find(|(_, lens)| lens.label == label) {
+                cur_box[i].focal_length = num;
+            } else {
+                cur_box.push(Lens {
+                    label: label.to_string(),
+                    focal_length: num,
+                });
+            }
+        } else {
+            let label = step.strip_suffix("-").unwrap();
+            let label_hash = hash(label);
+            let cur_box = &mut boxes[label_hash as usize];
+            if let Some((i, _)) = cur_box.iter().enumerate().find(|(_, lens)| lens.label == label) {
+                cur_box.remove(i);
+            }
+        }
+    }
+
+    let result = boxes
+        .iter()
+        .enumerate()
+        .map(|(box_no, lens_box)| {
+            lens_box
+                .iter()
+                .enumerate()
+                .map(|(lens_no, lens)| lens.focal_length * (box_no as u32 + 1) * (lens_no as u32 + 1))
+                .sum::<u32>()
+        })
+        .sum::<u32>();
+
+    result.into()
+}
+
+/// `size` steps on labels of up to six letters, about one label for every four steps so
+/// lenses get replaced and removed
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let labels: Vec<String> = (0..size / 4 + 1)
+        .map(|_| (0..rng.range(2..=6)).map(|_| (b'a' + rng.below(26) as u8) as char).collect())
+        .collect();
+    let steps: Vec<String> = (0..size.max(1))
+        .map(|_| {
+            let label = rng.pick(&labels);
+            if rng.chance(0.3) {
+                format!("{}-", label)
+            } else {
+                format!("{}={}", label, rng.range(1..=9))
+            }
+        })
+        .collect();
+    steps.join(",") + "\n"
+}
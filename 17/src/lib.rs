@@ -1,39 +1,38 @@
-use std::fs::read_to_string;
 use std::cmp::Ordering;
 use std::collections::{BinaryHeap, HashMap};
 
-#[derive(Copy, Clone, Eq, PartialEq, Hash)]
-enum Direction {
-    Up,
-    Down,
-    Left,
-    Right,
-}
+use aoc_common::{Answer, Direction, Grid, Params, ParseError, Pos, Rgb, Rng, Scene, Solution, Source};
 
-impl Direction {
-    fn opposite(&self) -> Direction {
-        match self {
-            Direction::Up => Direction::Down,
-            Direction::Down => Direction::Up,
-            Direction::Left => Direction::Right,
-            Direction::Right => Direction::Left,
-        }
+pub struct Day17;
+
+impl Solution for Day17 {
+    type Input = Grid<usize>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse(input)
     }
 
-    fn get_diff(&self) -> (isize, isize) {
-        match self {
-            Direction::Up => (-1, 0),
-            Direction::Down => (1, 0),
-            Direction::Left => (0, -1),
-            Direction::Right => (0, 1)
-        }
+    fn part1(input: &Self::Input, _params: &Params) -> Result<Answer, String> {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input, _params: &Params) -> Result<Answer, String> {
+        part2(input)
+    }
+
+    fn render(input: &Self::Input, _params: &Params, part: u8) -> Option<Scene> {
+        render(input, part)
+    }
+
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        generate(rng, size)
     }
 }
 
 #[derive(Copy, Clone, Eq, PartialEq)]
 struct State {
     cost: usize,
-    position: (usize, usize),
+    position: Pos,
     direction: Direction,
     steps_direction: usize,
 }
@@ -60,25 +59,36 @@ impl PartialOrd for State {
 
 #[derive(Clone, Copy, Eq, PartialEq, Hash)]
 struct DistKey {
-    position: (usize, usize),
+    position: Pos,
     direction: Direction,
     steps_direction: usize,
 }
 
+// The positions on the shortest path to `key`, from the start
+fn trace_back(previous: &HashMap<DistKey, DistKey>, mut key: DistKey) -> Vec<Pos> {
+    let mut path = vec![key.position];
+    while let Some(&before) = previous.get(&key) {
+        path.push(before.position);
+        key = before;
+    }
+    path.reverse();
+    path
+}
+
 // Dijkstra's shortest path algorithm.
 
 // Start at `start` and use `dist` to track the current shortest distance
 // to each node. This implementation isn't memory-efficient as it may leave duplicate
 // nodes in the queue. It also uses `usize::MAX` as a sentinel value,
 // for a simpler implementation.
-fn shortest_path(field: &Vec<Vec<usize>>, start: (usize, usize), goal: (usize, usize)) -> Option<usize> {
+fn shortest_path(field: &Grid<usize>, start: Pos, goal: Pos) -> Option<(usize, Vec<Pos>)> {
     // dist[node] = current shortest distance from `start` to `node`
     let mut dist: HashMap<DistKey, usize> = HashMap::new();
+    // The node before each node on its shortest path
+    let mut previous: HashMap<DistKey, DistKey> = HashMap::new();
 
     let mut heap = BinaryHeap::new();
 
-    let directions = vec![Direction::Up, Direction::Down, Direction::Left, Direction::Right];
-
     // We're at `start`, with a zero cost
     dist.insert(DistKey { position: start, direction: Direction::Right, steps_direction: 0 }, 0);
     dist.insert(DistKey { position: start, direction: Direction::Down, steps_direction: 0 }, 0);
@@ -87,37 +97,31 @@ fn shortest_path(field: &Vec<Vec<usize>>, start: (usize, usize), goal: (usize, u
     // Examine the frontier with lower cost nodes first (min-heap)
     while let Some(State { cost, position, direction, steps_direction }) = heap.pop() {
         // Alternatively we could have continued to find all shortest paths
-        if position == goal { return Some(cost); }
+        let dist_key = DistKey { position, direction, steps_direction };
+        if position == goal { return Some((cost, trace_back(&previous, dist_key))); }
 
         // Important as we may have already found a better way
-        let dist_key = DistKey { position, direction, steps_direction };
         if dist.contains_key(&dist_key) && cost > dist[&dist_key] { continue; }
 
         // For each node we can reach, see if we can find a way with
         // a lower cost going through this node
-        for dir in directions.iter().filter(|&d| *d != direction.opposite()) {
-            let (diff_x, diff_y) = dir.get_diff();
-            if position.0 as isize + diff_x < 0 
-                    || position.1 as isize + diff_y < 0 
-                    || position.0 as isize + diff_x >= field.len() as isize 
-                    || position.1 as isize + diff_y >= field[0].len() as isize {
+        for dir in Direction::ALL.iter().filter(|&d| *d != direction.opposite()) {
+            let Some(new_position) = field.step(position, *dir) else {
                 continue;
-            }
-
-            let new_position = ((position.0 as isize + diff_x) as usize, (position.1 as isize + diff_y) as usize);
-
+            };
 
             let next = State { position: new_position, 
                                direction: *dir,
                                steps_direction: if *dir == direction { steps_direction + 1 } else { 1 }, 
-                               cost: cost + field[new_position.0][new_position.1] };
+                               cost: cost + field[new_position] };
 
-            let dist_key = DistKey { position: new_position, direction: *dir, steps_direction: next.steps_direction };
+            let next_key = DistKey { position: new_position, direction: *dir, steps_direction: next.steps_direction };
             // If so, add it to the frontier and continue
-            if next.steps_direction <= 3 && (!dist.contains_key(&dist_key) || next.cost < dist[&dist_key]) {
+            if next.steps_direction <= 3 && (!dist.contains_key(&next_key) || next.cost < dist[&next_key]) {
                 heap.push(next);
                 // Relaxation, we have now found a better way
-                dist.insert(dist_key, next.cost);
+                dist.insert(next_key, next.cost);
+                previous.insert(next_key, dist_key);
             }
         }
     }
@@ -126,14 +130,14 @@ fn shortest_path(field: &Vec<Vec<usize>>, start: (usize, usize), goal: (usize, u
     None
 }
 
-fn shortest_path2(field: &Vec<Vec<usize>>, start: (usize, usize), goal: (usize, usize)) -> Option<usize> {
+fn shortest_path2(field: &Grid<usize>, start: Pos, goal: Pos) -> Option<(usize, Vec<Pos>)> {
     // dist[node] = current shortest distance from `start` to `node`
     let mut dist: HashMap<DistKey, usize> = HashMap::new();
+    // The node before each node on its shortest path
+    let mut previous: HashMap<DistKey, DistKey> = HashMap::new();
 
     let mut heap = BinaryHeap::new();
 
-    let directions = vec![Direction::Up, Direction::Down, Direction::Left, Direction::Right];
-
     // We're at `start`, with a zero cost
     dist.insert(DistKey { position: start, direction: Direction::Right, steps_direction: 0 }, 0);
     dist.insert(DistKey { position: start, direction: Direction::Down, steps_direction: 0 }, 0);
@@ -142,37 +146,31 @@ fn shortest_path2(field: &Vec<Vec<usize>>, start: (usize, usize), goal: (usize,
     // Examine the frontier with lower cost nodes first (min-heap)
     while let Some(State { cost, position, direction, steps_direction }) = heap.pop() {
         // Alternatively we could have continued to find all shortest paths
-        if position == goal && steps_direction >= 4 { return Some(cost); }
+        let dist_key = DistKey { position, direction, steps_direction };
+        if position == goal && steps_direction >= 4 { return Some((cost, trace_back(&previous, dist_key))); }
 
         // Important as we may have already found a better way
-        let dist_key = DistKey { position, direction, steps_direction };
         if dist.contains_key(&dist_key) && cost > dist[&dist_key] { continue; }
 
         // For each node we can reach, see if we can find a way with
         // a lower cost going through this node
-        for dir in directions.iter().filter(|&d| *d != direction.opposite()) {
-            let (diff_x, diff_y) = dir.get_diff();
-            if position.0 as isize + diff_x < 0 
-                    || position.1 as isize + diff_y < 0 
-                    || position.0 as isize + diff_x >= field.len() as isize 
-                    || position.1 as isize + diff_y >= field[0].len() as isize {
+        for dir in Direction::ALL.iter().filter(|&d| *d != direction.opposite()) {
+            let Some(new_position) = field.step(position, *dir) else {
                 continue;
-            }
-
-            let new_position = ((position.0 as isize + diff_x) as usize, (position.1 as isize + diff_y) as usize);
-
+            };
 
             let next = State { position: new_position, 
                                direction: *dir,
                                steps_direction: if *dir == direction { steps_direction + 1 } else { 1 }, 
-                               cost: cost + field[new_position.0][new_position.1] };
+                               cost: cost + field[new_position] };
 
-            let dist_key = DistKey { position: new_position, direction: *dir, steps_direction: next.steps_direction };
+            let next_key = DistKey { position: new_position, direction: *dir, steps_direction: next.steps_direction };
             // If so, add it to the frontier and continue
-            if (direction == *dir || steps_direction >= 4) && next.steps_direction <= 10 && (!dist.contains_key(&dist_key) || next.cost < dist[&dist_key]) {
+            if (direction == *dir || steps_direction >= 4) && next.steps_direction <= 10 && (!dist.contains_key(&next_key) || next.cost < dist[&next_key]) {
                 heap.push(next);
                 // Relaxation, we have now found a better way
-                dist.insert(dist_key, next.cost);
+                dist.insert(next_key, next.cost);
+                previous.insert(next_key, dist_key);
             }
         }
     }
@@ -181,17 +179,43 @@ fn shortest_path2(field: &Vec<Vec<usize>>, start: (usize, usize), goal: (usize,
     None
 }
 
-fn main() {
-    let input = read_to_string("input.txt").unwrap()
-                    .lines()
-                    .filter(|line| !line.is_empty())
-                    .map(|line| line.chars().map(|c| c.to_digit(10).unwrap() as usize).collect::<Vec<_>>())
-                    .collect::<Vec<_>>();
+pub fn parse(input_str: &str) -> Result<Grid<usize>, ParseError> {
+    let source = Source::new(input_str);
+    let lines = source.grid(input_str, |c| c.is_ascii_digit(), "a digit")?;
+    if lines.is_empty() {
+        return Err(source.error_after(input_str, "a row of city blocks"));
+    }
+    Ok(Grid::from_lines(&lines, |c| c.to_digit(10).unwrap() as usize))
+}
+
+pub fn part1(input: &Grid<usize>) -> Result<Answer, String> {
+    let goal = Pos::new(input.height() - 1, input.width() - 1);
+    let (shortest_path, _) = shortest_path(input, Pos::new(0, 0), goal).ok_or("the crucible can't get to the factory")?;
+    Ok(shortest_path.into())
+}
 
-    let shortest_path = shortest_path(&input, (0, 0), (input.len() - 1, input[0].len() - 1)).unwrap();
-    println!("result 1: {}", shortest_path);
+pub fn part2(input: &Grid<usize>) -> Result<Answer, String> {
+    let goal = Pos::new(input.height() - 1, input.width() - 1);
+    let (shortest_path2, _) = shortest_path2(input, Pos::new(0, 0), goal).ok_or("the ultra crucible can't get to the factory")?;
+    Ok(shortest_path2.into())
+}
+
+// The city blocks darker the more heat they lose, with the path of the crucible of `part`
+fn render(input: &Grid<usize>, part: u8) -> Option<Scene> {
+    let goal = Pos::new(input.height() - 1, input.width() - 1);
+    let found = if part == 1 { shortest_path(input, Pos::new(0, 0), goal) } else { shortest_path2(input, Pos::new(0, 0), goal) };
+    let (_, path) = found?;
+    let cells = input.map(|&heat| Rgb::gray(255 - 22 * heat.min(9) as u8));
+    Some(Scene::grid(cells).path(path.into_iter().map(Scene::center), Rgb::RED))
+}
 
-    let shortest_path2 = shortest_path2(&input, (0, 0), (input.len() - 1, input[0].len() - 1)).unwrap();
-    println!("result 2: {}", shortest_path2);
-    
+/// A `size` by `size` map of heat losses, at least 5 by 5 so the ultra crucible can get
+/// to the end
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let size = size.max(5);
+    let mut map = Grid::new(size, size, 0);
+    for pos in map.positions() {
+        map[pos] = rng.range(1..=9);
+    }
+    map.to_string()
 }
@@ -0,0 +1,325 @@
+use std::collections::HashMap;
+
+use aoc_common::{Answer, Params, ParseError, Pos, Rgb, Rng, Scene, Solution, Source};
+
+pub struct Day18;
+
+impl Solution for Day18 {
+    type Input = Plans;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse(input)
+    }
+
+    fn part1(input: &Self::Input, _params: &Params) -> Result<Answer, String> {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input, _params: &Params) -> Result<Answer, String> {
+        part2(input)
+    }
+
+    fn render(input: &Self::Input, _params: &Params, part: u8) -> Option<Scene> {
+        Some(render(input, part))
+    }
+
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        generate(rng, size)
+    }
+}
+
+// Part 2 reads the real instructions out of the colors
+pub struct Plans {
+    instructions: Vec<DigInstruction>,
+    instructions2: Vec<DigInstruction>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Direction {
+    Up,
+    Down,
+    Left,
+    Right
+}
+
+struct DigInstruction {
+    direction: Direction,
+    distance: u32,
+}
+
+impl DigInstruction {
+    fn parse(source: &Source, input: &str) -> Result<Self, ParseError> {
+        let (dir_str, rest) = source.split_once(input, " ")?;
+        let (dist_str, _) = source.split_once(rest, " ")?;
+        let direction = match dir_str {
+            "U" => Direction::Up,
+            "D" => Direction::Down,
+            "L" => Direction::Left,
+            "R" => Direction::Right,
+            _ => return Err(source.error(dir_str, "`U`, `D`, `L` or `R`"))
+        };
+        let distance = source.number(dist_str)?;
+        Ok(Self {
+            direction,
+            distance
+        })
+    }
+
+    fn parse2(source: &Source, input: &str) -> Result<Self, ParseError> {
+        let (_, rest) = source.split_once(input, " ")?;
+        let (_, color) = source.split_once(rest, " ")?;
+        let color = source.strip_prefix(color, "(#")?;
+        let color = source.strip_suffix(color, ")")?;
+        if color.len() != 6 || !color.chars().all(|c| c.is_ascii_hexdigit()) {
+            return Err(source.error(color, "six hex digits"));
+        }
+        let direction2 = match &color[5..6] {
+            "0" => Direction::Right,
+            "1" => Direction::Down,
+            "2" => Direction::Left,
+            "3" => Direction::Up,
+            _ => return Err(source.error(&color[5..], "a direction from `0` to `3`"))
+        };
+        let distance2 = u32::from_str_radix(&color[..5], 16).unwrap();
+        Ok(Self {
+            direction: direction2,
+            distance: distance2
+        })
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+struct LineEntry {
+    id: usize,
+    column: i32,
+    row: i32,
+    direction: Direction,
+    length: usize,
+}
+
+fn get_area(input: &Vec<DigInstruction>) -> Result<usize, String> {
+    // The scan below needs a trench that ends where it started and stays within i32
+    let mut corner = (0i64, 0i64);
+    for inst in input {
+        let distance = inst.distance as i64;
+        corner = match inst.direction {
+            Direction::Up => (corner.0 - distance, corner.1),
+            Direction::Down => (corner.0 + distance, corner.1),
+            Direction::Left => (corner.0, corner.1 - distance),
+            Direction::Right => (corner.0, corner.1 + distance),
+        };
+        if corner.0.abs() >= 1 << 30 || corner.1.abs() >= 1 << 30 {
+            return Err("the trench goes more than 2^30 cubes away from the start".to_string());
+        }
+    }
+    if corner != (0, 0) {
+        return Err("the trench doesn't end where it started".to_string());
+    }
+
+    let mut row_map: HashMap<i32, Vec<LineEntry>> = HashMap::new();
+    let mut row_map_end: HashMap<i32, Vec<usize>> = HashMap::new();
+
+    let mut cur_coords = (0, 0);
+    let mut id_counter = 0;
+    let mut min_row = 0;
+    let mut max_row = 0;
+    let mut result = 0;
+    for inst in input {
+        match inst.direction {
+            Direction::Up => {
+                let line_entry = LineEntry {
+                    id: id_counter,
+                    direction: Direction::Up,
+                    column: cur_coords.1,
+                    row: cur_coords.0 - inst.distance as i32,
+                    length: inst.distance as usize
+                };
+                id_counter += 1;
+                result += inst.distance as usize + 1;
+                //row_map_end.entry(cur_coords.0 + 1).or_insert(line_entry.id);
+                row_map_end.entry(cur_coords.0 + 1).or_default().push(line_entry.id);
+                cur_coords = (cur_coords.0 - inst.distance as i32, cur_coords.1);
+                row_map.entry(cur_coords.0).or_default().push(line_entry);
+            },
+            Direction::Down => {
+                let line_entry = LineEntry {
+                    id: id_counter,
+                    direction: Direction::Down,
+                    column: cur_coords.1,
+                    row: cur_coords.0,
+                    length: inst.distance as usize
+                };
+                result += inst.distance as usize + 1;
+                id_counter += 1;
+                row_map.entry(cur_coords.0).or_default().push(line_entry);
+                cur_coords = (cur_coords.0 + inst.distance as i32, cur_coords.1);
+                row_map_end.entry(cur_coords.0 + 1).or_default().push(line_entry.id);
+            },
+            Direction::Left => {
+                cur_coords = (cur_coords.0, cur_coords.1 - inst.distance as i32);
+            },
+            Direction::Right => {
+                cur_coords = (cur_coords.0, cur_coords.1 + inst.distance as i32);
+            }
+        }
+        if cur_coords.0 < min_row {
+            min_row = cur_coords.0;
+        }
+        if cur_coords.0 > max_row {
+            max_row = cur_coords.0;
+        }
+    }
+
+    let mut active_lines: Vec<LineEntry> = Vec::new();
+    // Looping thorugh all rows is not the most efficient way,
+    // but it runs in under a second with --release
+    // More effient would be just looking at the rows where the lines start/end
+    // and infering the rows between that from those
+    for row in min_row..=max_row {
+        let mut line_result = 0;
+        if let Some(entry) = row_map.get(&row) {
+            active_lines.extend_from_slice(entry);
+            active_lines.sort_by_key(|e| e.column);
+        }
+        if let Some(id) = row_map_end.get(&row) {
+            active_lines.retain(|e| !id.contains(&e.id));
+        }
+
+        let &entry = active_lines.first().ok_or("the trench never goes up or down")?;
+        let mut last_col = entry.column;
+        let mut counter: i32 = 0;
+        let start_end = entry.row == row || entry.row + entry.length as i32 == row;
+        if entry.direction == Direction::Up {
+            if start_end {
+                counter += 1;
+            } else {
+                counter += 2;
+            }
+        } else {
+            if start_end {
+                counter -= 1;
+            } else {
+                counter -= 2;
+            }
+        }
+
+        for entry in active_lines.iter().skip(1) {
+            if counter != 0 {
+                line_result += (entry.column - last_col - 1).unsigned_abs() as usize;
+            }
+            let start_end = entry.row == row || entry.row + entry.length as i32 == row;
+            if entry.direction == Direction::Up {
+                if start_end {
+                    counter += 1;
+                } else {
+                    counter += 2;
+                }
+            } else {
+                if start_end {
+                    counter -= 1;
+                } else {
+                    counter -= 2;
+                }
+            }
+            last_col = entry.column;
+        }
+        result += line_result;
+    }
+    Ok(result)
+}
+
+pub fn parse(input_str: &str) -> Result<Plans, ParseError> {
+    let source = Source::new(input_str);
+
+    let instructions = input_str.lines()   
+                .filter(|l| !l.is_empty())
+                .map(|l| DigInstruction::parse(&source, l))
+                .collect::<Result<Vec<_>, _>>()?;
+
+    let instructions2 = input_str.lines()   
+                .filter(|l| !l.is_empty())
+                .map(|l| DigInstruction::parse2(&source, l))
+                .collect::<Result<Vec<_>, _>>()?;
+
+    Ok(Plans { instructions, instructions2 })
+}
+
+pub fn part1(plans: &Plans) -> Result<Answer, String> {
+    let result1 = get_area(&plans.instructions)?;
+
+    Ok(result1.into())
+}
+
+pub fn part2(plans: &Plans) -> Result<Answer, String> {
+    let result2 = get_area(&plans.instructions2)?;
+
+    Ok(result2.into())
+}
+
+// The lagoon of the dig plan of `part`, the trench runs through the middle of its cubes
+fn render(plans: &Plans, part: u8) -> Scene {
+    let instructions = if part == 1 { &plans.instructions } else { &plans.instructions2 };
+    let mut corners = vec![(0i64, 0i64)];
+    for inst in instructions {
+        let (x, y) = corners[corners.len() - 1];
+        let distance = inst.distance as i64;
+        corners.push(match inst.direction {
+            Direction::Up => (x, y - distance),
+            Direction::Down => (x, y + distance),
+            Direction::Left => (x - distance, y),
+            Direction::Right => (x + distance, y),
+        });
+    }
+
+    let min_x = corners.iter().map(|c| c.0).min().unwrap();
+    let max_x = corners.iter().map(|c| c.0).max().unwrap();
+    let min_y = corners.iter().map(|c| c.1).min().unwrap();
+    let max_y = corners.iter().map(|c| c.1).max().unwrap();
+    let points = corners.iter().map(|&(x, y)| ((x - min_x) as f64 + 0.5, (y - min_y) as f64 + 0.5));
+    Scene::new((max_x - min_x + 1) as f64, (max_y - min_y + 1) as f64)
+        .polygon(points, Rgb(110, 70, 40), Some(Rgb(150, 200, 235)))
+}
+
+/// A trench around a blob on a `size` by `size` grid with sides of up to 10 meters per
+/// grid step for part 1. Part 2 digs the blob mirrored along the diagonal, as far as the
+/// five hex digits allow.
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let points = size.max(1) + 1;
+    let path = rng.closed_loop(points);
+    // Stretching whole rows and columns keeps the trench from touching itself
+    let stretch = |rng: &mut Rng, max: i64| -> Vec<i64> {
+        let mut at = 0;
+        (0..points)
+            .map(|_| {
+                at += rng.range(1..=max);
+                at
+            })
+            .collect()
+    };
+    let (rows, cols) = (stretch(rng, 10), stretch(rng, 10));
+    let (rows2, cols2) = (stretch(rng, 0xfffff / points as i64), stretch(rng, 0xfffff / points as i64));
+
+    // Every instruction goes from one corner to the next
+    let n = path.len();
+    let corners: Vec<Pos> = (0..n)
+        .filter(|&i| {
+            let (before, after) = (path[(i + n - 1) % n], path[(i + 1) % n]);
+            before.row != after.row && before.col != after.col
+        })
+        .map(|i| path[i])
+        .collect();
+
+    let mut out = String::new();
+    for (i, &from) in corners.iter().enumerate() {
+        let to = corners[(i + 1) % corners.len()];
+        let ((direction, distance), (direction2, distance2)) = if from.row == to.row {
+            let (a, b) = (from.col, to.col);
+            ((if b > a { 'R' } else { 'L' }, cols[b].abs_diff(cols[a])), (if b > a { 1 } else { 3 }, rows2[b].abs_diff(rows2[a])))
+        } else {
+            let (a, b) = (from.row, to.row);
+            ((if b > a { 'D' } else { 'U' }, rows[b].abs_diff(rows[a])), (if b > a { 0 } else { 2 }, cols2[b].abs_diff(cols2[a])))
+        };
+        out += &format!("{} {} (#{:05x}{})\n", direction, distance, distance2, direction2);
+    }
+    out
+}
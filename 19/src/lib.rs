@@ -0,0 +1,295 @@
+use std::{collections::BTreeMap, ops::Range};
+
+use aoc_common::{Answer, Param, Params, ParseError, Rng, Solution, Source};
+
+pub struct Day19;
+
+impl Solution for Day19 {
+    type Input = System;
+
+    const PARAMS: &'static [Param] = &[MIN_RATING, MAX_RATING];
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse(input)
+    }
+
+    fn part1(input: &Self::Input, _params: &Params) -> Result<Answer, String> {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input, params: &Params) -> Result<Answer, String> {
+        part2(input, params.get(&MIN_RATING) as i32, params.get(&MAX_RATING) as i32)
+    }
+
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        generate(rng, size)
+    }
+}
+
+pub struct System {
+    workflows: BTreeMap<String, Workflow>,
+    parts: Vec<BTreeMap<String, i32>>,
+}
+
+enum Operator {
+    GreaterThan,
+    LessThan
+}
+
+const CATEGORIES: [&str; 4] = ["x", "m", "a", "s"];
+
+const MIN_RATING: Param = Param { name: "min_rating", default: 1, help: "lowest possible rating (part 2)" };
+const MAX_RATING: Param = Param { name: "max_rating", default: 4000, help: "highest possible rating (part 2)" };
+
+struct Rule {
+    field: String,
+    operator: Operator,
+    value: i32,
+    target: String
+}
+
+impl Rule {
+    fn from_str(source: &Source, s: &str) -> Result<Self, ParseError> {
+        let (rule, target) = source.split_once(s, ":")?;
+        let rule = if let Some((field, value)) = rule.split_once("<") {
+            Self {
+                field: field.to_string(),
+                operator: Operator::LessThan,
+                value: source.number(value)?,
+                target: target.to_string()
+            }
+        } else {
+            if let Some((field, value)) = rule.split_once(">") {
+                Self {
+                    field: field.to_string(),
+                    operator: Operator::GreaterThan,
+                    value: source.number(value)?,
+                    target: target.to_string()
+                }
+            } else {
+                return Err(source.error_after(rule, "`<` or `>`"));
+            }
+        };
+        if !CATEGORIES.contains(&rule.field.as_str()) {
+            return Err(source.error(s, "`x`, `m`, `a` or `s`"));
+        }
+        Ok(rule)
+    }
+}
+
+struct Workflow {
+    name: String,
+    rules: Vec<Rule>,
+    catchall_target: String
+}
+
+impl Workflow {
+    fn from_str(source: &Source, s: &str) -> Result<Self, ParseError> {
+        let mut rules = Vec::new();
+        let (name,rules_str) = source.split_once(s, "{")?;
+        let raw_rules = rules_str.split(",").collect::<Vec<_>>();
+        for rule in raw_rules.iter().take(raw_rules.len() - 1) {
+            let rule = Rule::from_str(source, rule)?;
+            rules.push(rule);
+        }
+        Ok(Self {
+            name: name.to_string(),
+            rules,
+            catchall_target: source.strip_suffix(raw_rules.last().unwrap(), "}")?.to_owned()
+        })
+    }
+
+    fn find_next_target(&self, input: &BTreeMap<String, i32>) -> String {
+        for rule in self.rules.iter() {
+            let value = input.get(&rule.field).unwrap();
+            match rule.operator {
+                Operator::GreaterThan => {
+                    if value > &rule.value {
+                        return rule.target.clone();
+                    }
+                },
+                Operator::LessThan => {
+                    if value < &rule.value {
+                        return rule.target.clone();
+                    }
+                }
+            }
+        }
+        self.catchall_target.clone()
+    }
+}
+
+// `depth` counts the workflows on the way here, past the number of workflows they go in
+// circles
+fn find_num_accepted(workflows: &BTreeMap<String, Workflow>, current_workflow: &str, ranges: BTreeMap<String, Range<i32>>, depth: usize) -> Result<u64, String> {
+    let too_many = || "the accepted combinations don't fit in 64 bits".to_string();
+    let add = |result: u64, n: u64| result.checked_add(n).ok_or_else(too_many);
+    if current_workflow == "A" {
+        let result = ranges.iter().try_fold(1u64, |acc, (_, f)| acc.checked_mul((f.len() + 1) as u64));
+        return result.ok_or_else(too_many);
+    }
+    if current_workflow == "R" {
+        return Ok(0);
+    }
+    if depth > workflows.len() {
+        return Err(format!("the workflows lead from {} back to it", current_workflow));
+    }
+    let mut result: u64 = 0;
+    let current_workflow = workflows.get(current_workflow).unwrap();
+    let mut remaining_ranges = ranges.clone();
+    for rule in current_workflow.rules.iter() {
+        let mut new_ranges = remaining_ranges.clone();
+        match rule.operator {
+            Operator::GreaterThan => {
+                let range = &remaining_ranges[&rule.field];
+                if range.start > rule.value {
+                    return add(result, find_num_accepted(workflows, &rule.target, remaining_ranges, depth + 1)?);
+                } else {
+                    if range.end > rule.value {
+                        new_ranges.insert(rule.field.clone(), rule.value+1..range.end);
+                        remaining_ranges.insert(rule.field.clone(), range.start..rule.value);
+                    }
+                }
+            },
+            Operator::LessThan => {
+                let range = &remaining_ranges[&rule.field];
+                if range.end < rule.value {
+                    return add(result, find_num_accepted(workflows, &rule.target, remaining_ranges, depth + 1)?);
+                } else {
+                    if range.start < rule.value {
+                        new_ranges.insert(rule.field.clone(), range.start..rule.value-1);
+                        remaining_ranges.insert(rule.field.clone(), rule.value..range.end);
+                    }
+                }
+            }
+        }
+        result = add(result, find_num_accepted(workflows, &rule.target, new_ranges, depth + 1)?)?;
+    }
+    add(result, find_num_accepted(workflows, &current_workflow.catchall_target, remaining_ranges, depth + 1)?)
+}
+
+fn parse_workflows(source: &Source, workflows_str: &str) -> Result<BTreeMap<String, Workflow>, ParseError> {
+    let workflows = workflows_str.split("\n")
+        .map(|s| {let w = Workflow::from_str(source, s)?; Ok((w.name.clone(), w))})
+        .collect::<Result<BTreeMap<String, Workflow>, ParseError>>()?;
+    if !workflows.contains_key("in") {
+        return Err(source.error_after(workflows_str, "a workflow named `in`"));
+    }
+    // Every target is a workflow or accepts or rejects
+    for line in workflows_str.split("\n") {
+        let (_, rules) = source.split_once(line, "{")?;
+        for rule in source.strip_suffix(rules, "}")?.split(",") {
+            let target = rule.split_once(":").map_or(rule, |(_, target)| target);
+            if target != "A" && target != "R" && !workflows.contains_key(target) {
+                return Err(source.error(target, "`A`, `R` or the name of a workflow"));
+            }
+        }
+    }
+    Ok(workflows)
+}
+
+pub fn parse(input_str: &str) -> Result<System, ParseError> {
+    let source = Source::new(input_str);
+    let (workflows_str, input_str) = source.split_once(input_str, "\n\n")?;
+
+    let workflows = parse_workflows(&source, workflows_str)?;
+
+    let mut parts = Vec::new();
+
+    for line in input_str.lines() {
+        let mut input = BTreeMap::new();
+        let mut in_line = source.strip_prefix(line, "{")?;
+        in_line = source.strip_suffix(in_line, "}")?;
+        for s in in_line.split(",") {
+            let (key, value) = source.split_once(s, "=")?;
+            input.insert(key.to_string(), source.number::<i32>(value)?);
+        }
+        if !CATEGORIES.iter().all(|c| input.contains_key(*c)) {
+            return Err(source.error(line, "ratings for `x`, `m`, `a` and `s`"));
+        }
+        parts.push(input);
+    }
+
+    Ok(System { workflows, parts })
+}
+
+pub fn part1(system: &System) -> Result<Answer, String> {
+    let workflows = &system.workflows;
+
+    let mut result: i64 = 0;
+
+    for (i, input) in system.parts.iter().enumerate() {
+        let mut current_workflow = &workflows["in"];
+
+        for steps in 0.. {
+            if steps > workflows.len() {
+                return Err(format!("the workflows send part {} around in circles", i + 1));
+            }
+            let next_target = current_workflow.find_next_target(input);
+            if next_target == "A" || next_target == "R" {
+                if next_target == "A" {
+                    result += input.values().map(|&v| v as i64).sum::<i64>();
+                }
+                break;
+            }
+            current_workflow = &workflows[&next_target];
+        }
+    }
+
+    Ok(result.into())
+}
+
+pub fn part2(system: &System, min_rating: i32, max_rating: i32) -> Result<Answer, String> {
+    let workflows = &system.workflows;
+
+    let mut ranges = BTreeMap::new();
+    for key in ["x", "a", "m", "s"] {
+        ranges.insert(key.to_string(), min_rating..max_rating);
+    }
+
+    let result = find_num_accepted(workflows, "in", ranges, 0)?;
+
+    Ok(result.into())
+}
+
+/// `size` workflows and `size` parts. Every workflow but `in` is the target of exactly one
+/// rule of an earlier workflow, so they form a tree without loops.
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let mut names = vec!["in".to_string()];
+    while names.len() < size.max(1) {
+        let name: String = (0..rng.range(2..=3)).map(|_| (b'a' + rng.below(26) as u8) as char).collect();
+        if !names.contains(&name) {
+            names.push(name);
+        }
+    }
+
+    // The rules of each workflow, the last target is the catch-all. Each new workflow takes
+    // a free target of an earlier one, the targets left over accept or reject.
+    let mut targets: Vec<Vec<Option<&str>>> = Vec::new();
+    let mut free: Vec<(usize, usize)> = Vec::new();
+    for (i, name) in names.iter().enumerate() {
+        if i > 0 {
+            let (workflow, slot) = free.swap_remove(rng.below(free.len()));
+            targets[workflow][slot] = Some(name);
+        }
+        let slots = rng.range(2..=4) as usize;
+        targets.push(vec![None; slots]);
+        free.extend((0..slots).map(|slot| (i, slot)));
+    }
+
+    let mut out = String::new();
+    for (name, targets) in names.iter().zip(targets) {
+        let targets: Vec<&str> = targets.into_iter().map(|t| t.unwrap_or_else(|| *rng.pick(&["A", "R"]))).collect();
+        let rules: Vec<String> = targets[..targets.len() - 1]
+            .iter()
+            .map(|target| format!("{}{}{}:{}", rng.pick(&CATEGORIES), rng.pick(&['<', '>']), rng.range(2..=3999), target))
+            .collect();
+        out += &format!("{}{{{},{}}}\n", name, rules.join(","), targets[targets.len() - 1]);
+    }
+    out.push('\n');
+    for _ in 0..size.max(1) {
+        let ratings: Vec<String> = CATEGORIES.iter().map(|c| format!("{}={}", c, rng.range(1..=4000))).collect();
+        out += &format!("{{{}}}\n", ratings.join(","));
+    }
+    out
+}
@@ -0,0 +1,260 @@
+use std::collections::{BTreeMap, VecDeque};
+
+use aoc_common::{cycle, Answer, Frame, Params, ParseError, Recording, Rng, Solution, Source};
+
+pub struct Day20;
+
+impl Solution for Day20 {
+    type Input = BTreeMap<String, Module>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse(input)
+    }
+
+    fn part1(input: &Self::Input, _params: &Params) -> Result<Answer, String> {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input, _params: &Params) -> Result<Answer, String> {
+        part2(input)
+    }
+
+    fn visualize(input: &Self::Input, _params: &Params, recording: &mut Recording) {
+        visualize(input, recording);
+    }
+
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        generate(rng, size)
+    }
+}
+
+
+#[derive(Eq, PartialEq, Ord, PartialOrd, Clone)]
+enum ModuleState {
+    FlipFlop { state: bool },
+    Conjunction { states: BTreeMap<String, bool> },
+    Broadcast,
+    None
+}
+
+#[derive(Eq, PartialEq, Ord, PartialOrd, Clone)]
+pub struct Module {
+    name: String,
+    state: ModuleState,
+    outputs: Vec<String>
+}
+
+impl Module {
+    fn from_str(source: &Source, input: &str) -> Result<Module, ParseError> {
+        let (nametype_str, outputs_str) = source.split_once(input, " -> ")?;
+        let (state, name) = if let Some(name) = nametype_str.strip_prefix("%") {
+            (ModuleState::FlipFlop { state: false }, name)
+        } else if let Some(name) = nametype_str.strip_prefix("&") {
+            (ModuleState::Conjunction { states: BTreeMap::new() }, name)
+        } else if nametype_str == "broadcaster" {
+            (ModuleState::Broadcast, "broadcaster")
+        } else {
+            (ModuleState::None, nametype_str)
+        };
+        let outputs = outputs_str.split(", ").map(|s| s.to_string()).collect();
+        Ok(Module { name: name.to_string(), state, outputs })
+    }
+}
+
+// Conjunctions that feed each other can keep a press going forever, the real inputs
+// send a few hundred pulses per press
+const MAX_PULSES: usize = 1 << 20;
+
+// Part 2 waits for periods as long as the counters of the inputs, 4000 or so in the real
+// ones
+const MAX_PRESSES: u64 = 1 << 20;
+
+// Sends the pulse of one button press through the modules, `on_pulse` gets every pulse
+// as (source, target, high) in the order they arrive
+fn push_button(modules: &mut BTreeMap<String, Module>, mut on_pulse: impl FnMut(&str, &str, bool)) -> Result<(), String> {
+    let mut simulation_queue: VecDeque<(String, String, bool)> = VecDeque::new();
+    simulation_queue.push_back(("button".to_string(), "broadcaster".to_string(), false));
+
+    let mut pulses = 0;
+    while let Some((prev, name, pulse)) = simulation_queue.pop_front() {
+        pulses += 1;
+        if pulses > MAX_PULSES {
+            return Err(format!("a button press sends more than {} pulses", MAX_PULSES));
+        }
+        on_pulse(&prev, &name, pulse);
+        if let Some(module) = modules.get_mut(&name) {
+            let new_pulse = match &mut module.state {
+                ModuleState::FlipFlop { state: s } => {
+                    if pulse {
+                        continue;
+                    }
+                    *s = !*s;
+                    *s
+                },
+                ModuleState::Conjunction { states } => {
+                    states.insert(prev, pulse);
+                    !states.values().all(|&v| v)
+                },
+                ModuleState::Broadcast => pulse,
+                ModuleState::None => continue,
+            };
+            for output in &module.outputs {
+                simulation_queue.push_back((module.name.to_owned(), output.to_owned(), new_pulse));
+            }
+        }
+    }
+    Ok(())
+}
+
+// The pulses of the first few button presses, as many as the puzzle text shows
+fn visualize(modules: &BTreeMap<String, Module>, recording: &mut Recording) {
+    let mut modules = modules.clone();
+    for press in 1..=4 {
+        let mut frame = Frame::text(format!("button press {}", press));
+        let pushed = push_button(&mut modules, |prev, name, pulse| {
+            frame.text.push(format!("{} -{}-> {}", prev, if pulse { "high" } else { "low" }, name));
+        });
+        recording.push(|| frame);
+        if pushed.is_err() {
+            break;
+        }
+    }
+}
+
+pub fn parse(input_str: &str) -> Result<BTreeMap<String, Module>, ParseError> {
+    let source = Source::new(input_str);
+    let mut modules: BTreeMap<String, Module> = input_str.lines()
+                .filter(|l| !l.is_empty())
+                .map(|s| {let m = Module::from_str(&source, s)?; Ok((m.name.to_owned(), m))})
+                .collect::<Result<_, ParseError>>()?;
+
+    let mut inputs = BTreeMap::new();
+
+    for (name, module) in &modules{
+        for output in  &module.outputs {
+            inputs.entry(output.to_owned()).or_insert(vec![]).push(name.to_owned());
+        }
+    }
+
+    for module in modules.values_mut() {
+        if let ModuleState::Conjunction { states } = &mut module.state {
+            // A conjunction nothing sends to has no inputs to remember
+            for input in inputs.get(&module.name).into_iter().flatten() {
+                states.insert(input.to_owned(), false);
+            }
+        }
+    }
+
+    Ok(modules)
+}
+
+pub fn part1(modules: &BTreeMap<String, Module>) -> Result<Answer, String> {
+    let mut modules1 = modules.clone();
+
+    let mut low_count = 0usize;
+    let mut high_count = 0usize;
+
+    for _ in 0..1000 {
+        push_button(&mut modules1, |_, _, pulse| {
+            if pulse {
+                high_count += 1;
+            } else {
+                low_count += 1;
+            }
+        })?;
+    }
+
+    Ok((low_count * high_count).into())
+}
+
+pub fn part2(modules: &BTreeMap<String, Module>) -> Result<Answer, String> {
+    let mut presses: u64 = 0;
+
+    let mut modules2 = modules.clone();
+
+    // rx gets a low pulse when all inputs of the conjunction before it (only one in my input,
+    // guessing this is general rule) sent it a high one. Each input does that periodically,
+    // the first two presses where it does give its offset and period and the CRT combines them
+    // (this is also only a hacky solution since it could be that during one button press all the
+    // inputs are true at some point but never all at the same time, but it works for my input)
+
+    let last = modules.iter().find(|m| m.1.outputs.contains(&"rx".to_string())).ok_or("no module sends pulses to rx")?.0.to_owned();
+    let inputs = modules.values().filter(|m| m.outputs.contains(&last)).count();
+    if inputs == 0 {
+        return Err(format!("no module sends pulses to {}", last));
+    }
+
+    let mut high_presses: BTreeMap<String, Vec<u64>> = BTreeMap::new();
+
+    while high_presses.len() < inputs || high_presses.values().any(|p| p.len() < 2) {
+        if presses == MAX_PRESSES {
+            return Err(format!("the inputs of {} don't all send it two high pulses in {} presses", last, MAX_PRESSES));
+        }
+        presses += 1;
+        push_button(&mut modules2, |prev, name, pulse| {
+            if pulse && name == last {
+                let input_presses = high_presses.entry(prev.to_owned()).or_default();
+                if input_presses.len() < 2 && input_presses.last() != Some(&presses) {
+                    input_presses.push(presses);
+                }
+            }
+        })?;
+    }
+
+    let congruences = high_presses.values().map(|p| (p[0] as i128, (p[1] - p[0]) as i128));
+    let (first, period) = cycle::crt(congruences).ok_or("the inputs of rx never line up")?;
+    let (first, period) = (first as u64, period as u64);
+    // The inputs are only periodic from their first high pulse on
+    let earliest = high_presses.values().map(|p| p[0]).max().unwrap_or(0);
+
+    earliest.saturating_sub(first).div_ceil(period).checked_mul(period).and_then(|n| n.checked_add(first))
+        .map(Answer::from)
+        .ok_or_else(|| "the inputs of rx line up after more than 2^64 presses".to_string())
+}
+
+/// Four binary counters of `size` flip-flops (2 to 15), built like the real inputs: a
+/// counter counts button presses until its conjunction sees all the bits of its period
+/// set, then resets it to 0 and sends a high pulse on to the conjunction before `rx`.
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let bits = size.clamp(2, 15);
+    let mut taken = vec!["rx".to_string()];
+    let mut name = |rng: &mut Rng| loop {
+        let name: String = (0..2).map(|_| (b'a' + rng.below(26) as u8) as char).collect();
+        if !taken.contains(&name) {
+            taken.push(name.clone());
+            return name;
+        }
+    };
+
+    let last = name(rng);
+    let mut lines = vec![format!("&{} -> rx", last)];
+    let mut starts = Vec::new();
+    for _ in 0..4 {
+        // Odd with the top bit set, so the lowest and the highest flip-flop feed the hub
+        let period = (1 << (bits - 1)) + 1 + 2 * rng.below(1 << (bits - 2));
+        let flip_flops: Vec<String> = (0..bits).map(|_| name(rng)).collect();
+        let (hub, inverter) = (name(rng), name(rng));
+        let mut hub_outputs = vec![inverter.clone()];
+        for (bit, flip_flop) in flip_flops.iter().enumerate() {
+            let mut outputs = Vec::new();
+            if let Some(next) = flip_flops.get(bit + 1) {
+                outputs.push(next.clone());
+            }
+            if period >> bit & 1 == 1 {
+                outputs.push(hub.clone());
+            }
+            if period >> bit & 1 == 0 || bit == 0 {
+                hub_outputs.push(flip_flop.clone());
+            }
+            rng.shuffle(&mut outputs);
+            lines.push(format!("%{} -> {}", flip_flop, outputs.join(", ")));
+        }
+        rng.shuffle(&mut hub_outputs);
+        lines.push(format!("&{} -> {}", hub, hub_outputs.join(", ")));
+        lines.push(format!("&{} -> {}", inverter, last));
+        starts.push(flip_flops[0].clone());
+    }
+    lines.push(format!("broadcaster -> {}", starts.join(", ")));
+    rng.shuffle(&mut lines);
+    lines.join("\n") + "\n"
+}
@@ -0,0 +1,247 @@
+use std::collections::{HashSet, HashMap, VecDeque, hash_map::Entry};
+
+use aoc_common::{Answer, Color, Frame, Grid, Param, Params, ParseError, Pos, Recording, Rgb, Rng, Scene, Solution, Source};
+
+pub struct Day21;
+
+impl Solution for Day21 {
+    type Input = Garden;
+
+    const PARAMS: &'static [Param] = &[STEPS, PART2_STEPS];
+
+    fn check_params(params: &Params) -> Result<(), String> {
+        params.get_usize(&STEPS)?;
+        params.get_usize(&PART2_STEPS).map(|_| ())
+    }
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse(input)
+    }
+
+    fn part1(input: &Self::Input, params: &Params) -> Result<Answer, String> {
+        Ok(part1(input, params.get_usize(&STEPS)?))
+    }
+
+    fn part2(input: &Self::Input, params: &Params) -> Result<Answer, String> {
+        Ok(part2(input, params.get_usize(&PART2_STEPS)?))
+    }
+
+    fn visualize(input: &Self::Input, params: &Params, recording: &mut Recording) {
+        if let Ok(steps) = params.get_usize(&STEPS) {
+            visualize(input, steps, recording);
+        }
+    }
+
+    // Part 2 walks too far to draw
+    fn render(input: &Self::Input, params: &Params, part: u8) -> Option<Scene> {
+        let steps = params.get_usize(&STEPS).ok()?;
+        (part == 1).then(|| render(input, steps))
+    }
+
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        generate(rng, size)
+    }
+}
+
+const STEPS: Param = Param { name: "steps", default: 64, help: "steps the elf takes (part 1)" };
+const PART2_STEPS: Param = Param { name: "part2_steps", default: 26501365, help: "steps the elf takes on the infinite garden (part 2)" };
+
+pub struct Garden {
+    grid: Grid<char>,
+    start: Pos,
+}
+
+type MemoTable = HashSet<((isize, isize), usize)>;
+
+fn dfs_rec(memo_table: &mut MemoTable, grid: &Grid<char>, pos: (isize, isize), steps_left: usize, end_positions: &mut HashSet<(isize, isize)>) {
+    if memo_table.contains(&(pos, steps_left)) {
+        return;
+    }
+    let (row, col) = pos;
+    if *grid.get_wrapped(row, col) == '#' {
+        return;
+    }
+    if steps_left == 0 {
+        end_positions.insert(pos);
+        return;
+    }
+    dfs_rec(memo_table, grid, (row-1, col), steps_left - 1, end_positions);
+    dfs_rec(memo_table, grid, (row+1, col), steps_left - 1, end_positions);
+    dfs_rec(memo_table, grid, (row, col-1), steps_left - 1, end_positions);
+    dfs_rec(memo_table, grid, (row, col+1), steps_left - 1, end_positions);
+    memo_table.insert((pos, steps_left));
+}
+
+fn bfs2(grid: &Grid<char>, start_position: Pos) -> HashMap<Pos, usize> {
+    // Number of grid elements not '#'
+    let mut visited_table: HashMap<Pos, usize> = HashMap::new();
+
+    let mut queue: VecDeque<(Pos, usize)> = VecDeque::new();
+    queue.push_back((start_position, 0));
+    while !queue.is_empty() {
+        let (pos, steps_taken) = queue.pop_front().unwrap();
+        if grid[pos] == '#' {
+            continue;
+        } 
+        match visited_table.entry(pos) {
+            Entry::Occupied(_) => continue,
+            Entry::Vacant(e) => { e.insert(steps_taken); }
+        }
+        for next in grid.neighbors4(pos) {
+            queue.push_back((next, steps_taken + 1));
+        }
+    }
+    visited_table
+}
+
+pub fn parse(input_str: &str) -> Result<Garden, ParseError> {
+    let source = Source::new(input_str);
+    let lines = source.grid(input_str, |c| "S.#".contains(c), "`.`, `#` or `S`")?;
+    let input = Grid::from_lines(&lines, |c| c);
+
+    // find the position of the S in the input
+    let start_position = input.position(|&c| c == 'S')
+            .ok_or_else(|| source.error_after(input_str, "a starting position `S`"))?;
+
+    Ok(Garden { grid: input, start: start_position })
+}
+
+fn walk(input: &Grid<char>, start_position: Pos, steps: usize) -> HashSet<(isize, isize)> {
+    let mut end_positions: HashSet<(isize, isize)> = HashSet::new();
+    let mut memo_table: MemoTable = HashSet::new();
+
+    dfs_rec(&mut memo_table, input, (start_position.row as isize, start_position.col as isize), steps, &mut end_positions);
+
+    end_positions
+}
+
+// The BFS frontier spreading from the start, with the plots the elf can be on after
+// exactly that many steps marked as in the puzzle text
+fn visualize(garden: &Garden, steps: usize, recording: &mut Recording) {
+    let distances = bfs2(&garden.grid, garden.start);
+    let max_distance = distances.values().copied().max().unwrap_or(0);
+    for step in 0..=steps.min(max_distance) {
+        recording.push(|| {
+            let mut grid = garden.grid.clone();
+            let reachable: Vec<Pos> = distances
+                .iter()
+                .filter(|&(_, &d)| d <= step && d % 2 == step % 2)
+                .map(|(&p, _)| p)
+                .collect();
+            for &pos in reachable.iter() {
+                grid[pos] = 'O';
+            }
+            let frontier = distances.iter().filter(|&(_, &d)| d == step).map(|(&p, _)| p);
+            Frame::grid(grid)
+                .highlight_all(reachable.iter().copied(), Color::Green)
+                .highlight_all(frontier, Color::Red)
+                .line(format!("step {}: {} garden plots reachable", step, reachable.len()))
+        });
+    }
+}
+
+// The plots reachable in exactly `steps` steps in green, the ones closer to the start with
+// the other parity in light green
+fn render(garden: &Garden, steps: usize) -> Scene {
+    let distances = bfs2(&garden.grid, garden.start);
+    let mut cells = garden.grid.map(|&c| if c == '#' { Rgb::gray(90) } else { Rgb::WHITE });
+    for (&pos, &d) in distances.iter().filter(|&(_, &d)| d <= steps) {
+        cells[pos] = if d % 2 == steps % 2 { Rgb::GREEN } else { Rgb(190, 230, 190) };
+    }
+    cells[garden.start] = Rgb::RED;
+    Scene::grid(cells)
+}
+
+pub fn part1(garden: &Garden, steps: usize) -> Answer {
+    let (input, start_position) = (&garden.grid, garden.start);
+
+    let end_positions = walk(input, start_position, steps);
+
+    end_positions.len().into()
+}
+
+// Number of plots reachable in exactly `steps` steps on the infinitely repeated garden.
+//
+// Only holds for gardens shaped like the real inputs: square, the start in the middle,
+// free rows and columns through the start and around the border, and `steps` ending
+// exactly on the border of a copy an even number `n` of copies away from the start.
+// The reachable plots then form a diamond of (n+1)^2 copies where the plots at an odd
+// distance from the start are reachable and n^2 copies with the even ones. The n+1
+// copies at the tips and edges of the diamond lose their odd corners (further than
+// half a garden from the middle), the n copies just outside of the edges add their
+// even corners.
+fn count_reachable(input: &Grid<char>, start_position: Pos, steps: usize) -> usize {
+    let half = input.width() / 2;
+    let n = steps / input.width();
+
+    let distances = bfs2(input, start_position);
+    let count = |odd: bool, corners: bool| {
+        distances.values().filter(|&&d| (d % 2 == 1) == odd && (!corners || d > half)).count()
+    };
+
+    (n + 1) * (n + 1) * count(true, false) + n * n * count(false, false)
+        - (n + 1) * count(true, true) + n * count(false, true)
+}
+
+pub fn part2(garden: &Garden, steps: usize) -> Answer {
+    count_reachable(&garden.grid, garden.start, steps).into()
+}
+
+/// A garden shaped like the real inputs so part 2 holds: `size` rounded up to odd, the
+/// start in the middle, free rows and columns through it and around the border, and
+/// rocks that never touch each other
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let size = size.max(5) | 1;
+    let mut garden = Grid::new(size, size, '.');
+    let free = |i: usize| i == 0 || i == size / 2 || i == size - 1;
+    for pos in garden.positions().collect::<Vec<_>>() {
+        if free(pos.row) || free(pos.col) || !rng.chance(0.3) {
+            continue;
+        }
+        if garden.neighbors8(pos).all(|n| garden[n] == '.') {
+            garden[pos] = '#';
+        }
+    }
+    garden[Pos::new(size / 2, size / 2)] = 'S';
+    garden.to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proptest::prelude::*;
+
+    const SIZE: usize = 11;
+
+    // Gardens like the real inputs but small: free lines through the middle and around the
+    // border, and rocks that never touch each other so every plot is reachable in its
+    // manhattan distance
+    fn garden() -> impl Strategy<Value = Grid<char>> {
+        proptest::collection::vec(any::<bool>(), SIZE * SIZE).prop_map(|rocks| {
+            let mut grid = Grid::new(SIZE, SIZE, '.');
+            let free = |i: usize| i == 0 || i == SIZE / 2 || i == SIZE - 1;
+            for pos in grid.positions().collect::<Vec<_>>() {
+                if free(pos.row) || free(pos.col) || !rocks[pos.row * SIZE + pos.col] {
+                    continue;
+                }
+                if grid.neighbors8(pos).all(|n| grid[n] == '.') {
+                    grid[pos] = '#';
+                }
+            }
+            grid[Pos::new(SIZE / 2, SIZE / 2)] = 'S';
+            grid
+        })
+    }
+
+    proptest! {
+        #![proptest_config(ProptestConfig::with_cases(32))]
+
+        #[test]
+        fn formula_matches_brute_force(grid in garden(), repeats in prop_oneof![Just(2usize), Just(4)]) {
+            let start = Pos::new(SIZE / 2, SIZE / 2);
+            let steps = repeats * SIZE + SIZE / 2;
+            let end_positions = walk(&grid, start, steps);
+            prop_assert_eq!(count_reachable(&grid, start, steps), end_positions.len(), "\n{}", grid);
+        }
+    }
+}
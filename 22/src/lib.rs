@@ -0,0 +1,267 @@
+use std::collections::BTreeMap;
+
+use aoc_common::{Answer, Color, Frame, Grid, Params, ParseError, Pos, Recording, Rng, Solution, Source};
+
+pub struct Day22;
+
+impl Solution for Day22 {
+    type Input = Vec<Brick>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse(input)
+    }
+
+    fn part1(input: &Self::Input, _params: &Params) -> Result<Answer, String> {
+        Ok(part1(input))
+    }
+
+    fn part2(input: &Self::Input, _params: &Params) -> Result<Answer, String> {
+        Ok(part2(input))
+    }
+
+    fn visualize(input: &Self::Input, _params: &Params, recording: &mut Recording) {
+        settle(input, recording);
+    }
+
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        generate(rng, size)
+    }
+}
+
+fn parse_coords(source: &Source, coords: &str) -> Result<(i32, i32, i32), ParseError> {
+    let (x, rest) = source.split_once(coords, ",")?;
+    let (y, z) = source.split_once(rest, ",")?;
+    Ok((source.number(x)?, source.number(y)?, source.number(z)?))
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct Brick {
+    id: usize,
+    coords1: (i32, i32, i32),
+    coords2: (i32, i32, i32),
+}
+
+impl Brick {
+    fn new(id: usize, coords1: (i32, i32, i32), coords2: (i32, i32, i32)) -> Self {
+        Self { id, coords1, coords2 }
+    }
+
+    fn intersects(&self, other: &Self) -> bool {
+        let (x1, y1, z1) = self.coords1;
+        let (x2, y2, z2) = self.coords2;
+        let highx = x1.max(x2);
+        let lowx = x1.min(x2);
+        let highy = y1.max(y2);
+        let lowy = y1.min(y2);
+        let highz = z1.max(z2);
+        let lowz = z1.min(z2);
+        let (x3, y3, z3) = other.coords1;
+        let (x4, y4, z4) = other.coords2;
+        let otherhighx = x3.max(x4);
+        let otherlowx = x3.min(x4);
+        let otherhighy = y3.max(y4);
+        let otherlowy = y3.min(y4);
+        let otherhighz = z3.max(z4);
+        let otherlowz = z3.min(z4);
+        let xintersects = (otherlowx >= lowx && otherlowx <= highx) || (otherhighx >= lowx && otherhighx <= highx) || (lowx >= otherlowx && lowx <= otherhighx) || (highx >= otherlowx && highx <= otherhighx);
+        let yintersects = (otherlowy >= lowy && otherlowy <= highy) || (otherhighy >= lowy && otherhighy <= highy) || (lowy >= otherlowy && lowy <= otherhighy) || (highy >= otherlowy && highy <= otherhighy);
+        let zintersects = (otherlowz >= lowz && otherlowz <= highz) || (otherhighz >= lowz && otherhighz <= highz) || (lowz >= otherlowz && lowz <= otherhighz) || (highz >= otherlowz && highz <= otherhighz);
+        xintersects && yintersects && zintersects
+    }
+
+    fn move_by(&self, x: i32, y: i32, z: i32) -> Self {
+        let (x1, y1, z1) = self.coords1;
+        let (x2, y2, z2) = self.coords2;
+        Self {
+            id: self.id,
+            coords1: (x1 + x, y1 + y, z1 + z),
+            coords2: (x2 + x, y2 + y, z2 + z),
+        }
+    }
+
+    fn get_height_above_ground(&self) -> i32 {
+        let (_, _, z1) = self.coords1;
+        let (_, _, z2) = self.coords2;
+        z1.min(z2)
+    }
+}
+
+pub fn parse(input_str: &str) -> Result<Vec<Brick>, ParseError> {
+    let source = Source::new(input_str);
+    input_str
+        .lines()
+        .filter(|line| !line.is_empty())
+        .enumerate()
+        .map(|(id, line)| {
+            let (coords1, coords2) = source.split_once(line, "~")?;
+            Ok(Brick::new(id, parse_coords(&source, coords1)?, parse_coords(&source, coords2)?))
+        })
+        .collect::<Result<Vec<_>, _>>()
+}
+
+type Links = BTreeMap<usize, Vec<usize>>;
+
+fn label(id: usize) -> char {
+    (b'A' + (id % 26) as u8) as char
+}
+
+// The stack seen from the front like in the puzzle text: x to the right, z up, `?` where
+// several bricks are behind each other. The bricks in `moved` are highlighted.
+fn front_view(bricks: &[Brick], moved: &[usize]) -> Frame {
+    let width = bricks.iter().map(|b| b.coords1.0.max(b.coords2.0)).max().unwrap_or(0) as usize + 1;
+    let height = bricks.iter().map(|b| b.coords1.2.max(b.coords2.2)).max().unwrap_or(0) as usize + 1;
+    let mut grid = Grid::new(width, height, '.');
+    for col in 0..width {
+        grid[Pos::new(height - 1, col)] = '-';
+    }
+
+    let mut highlights = Vec::new();
+    for brick in bricks {
+        let (x1, _, z1) = brick.coords1;
+        let (x2, _, z2) = brick.coords2;
+        for x in x1.min(x2)..=x1.max(x2) {
+            for z in z1.min(z2)..=z1.max(z2) {
+                let pos = Pos::new(height - 1 - z as usize, x as usize);
+                grid[pos] = if grid[pos] == '.' { label(brick.id) } else { '?' };
+                if moved.contains(&brick.id) {
+                    highlights.push(pos);
+                }
+            }
+        }
+    }
+    Frame::grid(grid).highlight_all(highlights, Color::Yellow)
+}
+
+// Lets all bricks fall down and returns them with the bricks each one is supported by and supports
+fn settle(input: &[Brick], recording: &mut Recording) -> (Vec<Brick>, Links, Links) {
+    let mut bricks = input.to_vec();
+    recording.push(|| front_view(&bricks, &[]).line("snapshot"));
+
+    let mut change = true;
+    while change {
+        let mut new_bricks = Vec::with_capacity(bricks.len());
+        let mut moved = Vec::new();
+        change = false;
+        for brick in &bricks {
+            let new_brick = if brick.get_height_above_ground() > 1 {
+                let potential_brick = brick.move_by(0, 0, -1);
+                let mut intersects = false;
+                for other_brick in bricks.iter().filter(|b| b.id != brick.id) {
+                    if potential_brick.intersects(other_brick) {
+                        intersects = true;
+                        break;
+                    }
+                }
+                if intersects {
+                    *brick
+                } else {
+                    change = true;
+                    moved.push(brick.id);
+                    potential_brick
+                }
+            } else {
+                *brick
+            };
+            new_bricks.push(new_brick);
+        }
+        bricks = new_bricks;
+        recording.push(|| front_view(&bricks, &moved).line(format!("{} bricks fell by one", moved.len())));
+    }
+
+    let mut supported_by: BTreeMap<usize, Vec<usize>> = BTreeMap::new();
+    let mut supports: BTreeMap<usize, Vec<usize>> = BTreeMap::new();
+
+    for (i, brick) in bricks.iter().enumerate() {
+        let down_brick = brick.move_by(0, 0, -1);
+        for (j, other_brick) in bricks.iter().enumerate() {
+            if i != j && down_brick.intersects(other_brick) {
+                supported_by.entry(i).or_default().push(j);
+                supports.entry(j).or_default().push(i);
+            }
+        }
+    }
+
+    recording.push(|| {
+        let mut frame = front_view(&bricks, &[]);
+        let names = |ids: &Vec<usize>| ids.iter().map(|&j| label(j).to_string()).collect::<Vec<_>>().join(", ");
+        for i in 0..bricks.len() {
+            let above = supports.get(&i).map_or("nothing".to_string(), names);
+            let below = supported_by.get(&i).map_or("the ground".to_string(), names);
+            frame.text.push(format!("{} supports {}, rests on {}", label(i), above, below));
+        }
+        frame
+    });
+
+    (bricks, supported_by, supports)
+}
+
+pub fn part1(input: &[Brick]) -> Answer {
+    let (bricks, supported_by, supports) = settle(input, &mut Recording::disabled());
+
+    let result1 = (0..bricks.len())
+        .filter(|i| supports.get(i)
+            .is_none_or(|s| s.iter().all(|j| supported_by[j].len() > 1)))
+        .count();
+
+    result1.into()
+}
+
+pub fn part2(input: &[Brick]) -> Answer {
+    let (bricks, mut supported_by, _) = settle(input, &mut Recording::disabled());
+
+    let mut result2 = 0;
+
+    // add imaginary "ground brick" to supported by for all bricks with height = 1
+    for (i, brick) in bricks.iter().enumerate() {
+        if brick.get_height_above_ground() == 1 {
+            supported_by.entry(i).or_default().push(usize::MAX);
+        }
+    }
+
+    for brick in &bricks {
+        let mut supported_by_temp = supported_by.clone();
+        // remove current brick from supported_by
+        supported_by_temp.iter_mut().for_each(|(_, l)| l.retain(|i| *i != brick.id));
+        let mut change = true;
+        while change {
+            change = false;
+            // Check if any bricks are now unsupported and remove them from supports
+            let unsupported = supported_by_temp.iter().filter(|(_, l)| l.is_empty()).map(|(i, _)| *i).collect::<Vec<_>>();
+            for brick_id in &unsupported {
+                supported_by_temp.iter_mut().for_each(|l| l.1.retain(|i| *i != *brick_id));
+                supported_by_temp.remove(brick_id);
+                change = true;
+            }
+            result2 += unsupported.len();
+        }
+    }
+
+    result2.into()
+}
+
+/// `size` bricks of up to four cubes over a 10 by 10 area like the real inputs, in the
+/// air one above the other so none of them overlap before they fall
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let mut z = 1 + rng.below(5) as i32;
+    let mut lines = Vec::new();
+    for _ in 0..size {
+        let length = rng.below(4) as i32;
+        let (mut x, mut y) = (rng.below(10) as i32, rng.below(10) as i32);
+        let mut end = (x, y, z);
+        match rng.below(3) {
+            0 => {
+                x = x.min(9 - length);
+                end = (x + length, y, z);
+            }
+            1 => {
+                y = y.min(9 - length);
+                end = (x, y + length, z);
+            }
+            _ => end.2 += length,
+        }
+        lines.push(format!("{},{},{}~{},{},{}", x, y, z, end.0, end.1, end.2));
+        z = end.2 + 1 + rng.below(3) as i32;
+    }
+    rng.shuffle(&mut lines);
+    lines.join("\n") + "\n"
+}
@@ -0,0 +1,327 @@
+use std::collections::{VecDeque, HashMap};
+
+use petgraph::{graph::DiGraph, graph::NodeIndex};
+
+use aoc_common::{Answer, Direction, Grid, Params, ParseError, Pos, Rgb, Rng, Scene, Solution, Source};
+
+pub struct Day23;
+
+impl Solution for Day23 {
+    type Input = Grid<char>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse(input)
+    }
+
+    fn part1(input: &Self::Input, _params: &Params) -> Result<Answer, String> {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input, _params: &Params) -> Result<Answer, String> {
+        part2(input)
+    }
+
+    fn render(input: &Self::Input, _params: &Params, part: u8) -> Option<Scene> {
+        render(input, part)
+    }
+
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        generate(rng, size)
+    }
+}
+
+// The direction a slope can be walked down
+fn slope(c: char) -> Option<Direction> {
+    match c {
+        '^' => Some(Direction::Up),
+        'v' => Some(Direction::Down),
+        '<' => Some(Direction::Left),
+        '>' => Some(Direction::Right),
+        _ => None,
+    }
+}
+
+// We take out all of the single way paths and connect junctions in a graph
+fn construct_graph_bfs(grid: &Grid<char>) -> (NodeIndex, NodeIndex, DiGraph<Pos, usize>) {
+    let mut graph: DiGraph<Pos, usize> = DiGraph::new();
+    let mut node_map = HashMap::new();
+    let start_pos = Pos::new(0, 1);
+    let end_pos = Pos::new(grid.height() - 1, grid.width() - 2);
+    let start_node = graph.add_node(start_pos);
+    node_map.insert(start_pos, start_node);
+    let end_node = graph.add_node(end_pos);
+    node_map.insert( end_pos, end_node);
+    let mut queue = VecDeque::new();
+    queue.push_back((start_node, Pos::new(start_pos.row + 1, start_pos.col)));
+    let mut visited = Grid::new(grid.width(), grid.height(), false);
+    visited[start_pos] = true;
+    while let Some((init_node, pos)) = queue.pop_front() {
+        let mut path_length = 1;
+        let mut current = pos;
+        let mut forward_possible = true;
+        let mut backward_possible = true;
+        loop {
+            visited[current] = true;
+            let mut neighbors = vec![];
+            for dir in Direction::ALL {
+                let Some(next) = grid.step(current, dir).filter(|&n| grid[n] != '#') else {
+                    continue;
+                };
+                let mut forward_possible = forward_possible;
+                let mut backward_possible = backward_possible;
+                // Walking down a slope can't be undone, walking up one is impossible
+                if slope(grid[current]) == Some(dir) {
+                    backward_possible = false;
+                } else if slope(grid[current]) == Some(dir.opposite()) {
+                    forward_possible = false;
+                }
+                neighbors.push(((forward_possible, backward_possible), next));
+            }
+            let neighbor_node = neighbors.iter().filter_map(|(_, n)| node_map.get(n)).find(|n| **n != init_node);
+            if let Some(neighbor_node) = neighbor_node {
+                if forward_possible {
+                    graph.add_edge(init_node, *neighbor_node, path_length + 1);
+                }
+                if backward_possible {
+                    graph.add_edge(*neighbor_node, init_node, path_length + 1);
+                }
+                break;
+            }
+            let unvisited_neighbors = neighbors.iter().filter(|(_, n)| !visited[*n]).collect::<Vec<_>>();
+            if unvisited_neighbors.len() == 1 {
+                current = unvisited_neighbors[0].1;
+                forward_possible = unvisited_neighbors[0].0.0;
+                backward_possible = unvisited_neighbors[0].0.1;
+                path_length += 1;
+                visited[current] = true;
+            } else {
+                let node = graph.add_node(current);
+                node_map.insert(current, node);
+                if forward_possible {
+                    graph.add_edge(init_node, node, path_length);
+                }
+                if backward_possible {
+                    graph.add_edge(node, init_node, path_length);
+                }
+                for neighbor in unvisited_neighbors {
+                    queue.push_back((node, neighbor.1));
+                }
+                break;
+            }
+        }
+    }
+    (start_node, end_node, graph)
+}
+
+fn construct_graph_bfs2(grid: &Grid<char>) -> (NodeIndex, NodeIndex, DiGraph<Pos, usize>) {
+    let mut graph: DiGraph<Pos, usize> = DiGraph::new();
+    let mut node_map = HashMap::new();
+    let start_pos = Pos::new(0, 1);
+    let end_pos = Pos::new(grid.height() - 1, grid.width() - 2);
+    let start_node = graph.add_node(start_pos);
+    node_map.insert(start_pos, start_node);
+    let end_node = graph.add_node(end_pos);
+    node_map.insert( end_pos, end_node);
+    let mut queue = VecDeque::new();
+    queue.push_back((start_node, Pos::new(start_pos.row + 1, start_pos.col)));
+    let mut visited = Grid::new(grid.width(), grid.height(), false);
+    visited[start_pos] = true;
+    while let Some((init_node, pos)) = queue.pop_front() {
+        let mut path_length = 1;
+        let mut current = pos;
+        loop {
+            visited[current] = true;
+            let neighbors = grid.neighbors4(current).filter(|&n| grid[n] != '#').collect::<Vec<_>>();
+            let neighbor_node = neighbors.iter().filter_map(|n| node_map.get(n)).find(|n| **n != init_node);
+            if let Some(neighbor_node) = neighbor_node {
+                graph.add_edge(init_node, *neighbor_node, path_length + 1);
+                graph.add_edge(*neighbor_node, init_node, path_length + 1);
+                break;
+            }
+            let unvisited_neighbors = neighbors.iter().filter(|n| !visited[**n]).collect::<Vec<_>>();
+            if unvisited_neighbors.len() == 1 {
+                current = *unvisited_neighbors[0];
+                path_length += 1;
+                visited[current] = true;
+            } else {
+                let node = graph.add_node(current);
+                node_map.insert(current, node);
+                graph.add_edge(init_node, node, path_length);
+                graph.add_edge(node, init_node, path_length);
+                for neighbor in unvisited_neighbors {
+                    queue.push_back((node, *neighbor));
+                }
+                break;
+            }
+        }
+    }
+    (start_node, end_node, graph)
+}
+
+fn get_len(graph: &DiGraph<Pos, usize>, path: &[NodeIndex]) -> usize {
+    let mut len = 0;
+    for i in 0..path.len() - 1 {
+        let edge = graph.find_edge(path[i], path[i + 1]).unwrap();
+        len += graph[edge];
+    }
+    len
+}
+
+pub fn parse(input_str: &str) -> Result<Grid<char>, ParseError> {
+    let source = Source::new(input_str);
+    let lines = source.grid(input_str, |c| "#.<>^v".contains(c), "`#`, `.` or a slope")?;
+    // The hike starts in the second column of the top row and goes down from there
+    if lines.len() < 3 || lines[0].len() < 3 {
+        return Err(source.error_after(input_str.trim_end(), "a forest at least 3 tiles wide and high"));
+    }
+    if &lines[0][1..2] != "." {
+        return Err(source.error(&lines[0][1..], "the start of the trail"));
+    }
+    if &lines[1][1..2] == "#" {
+        return Err(source.error(&lines[1][1..], "a trail down from the start"));
+    }
+    let last = lines[lines.len() - 1];
+    if &last[last.len() - 2..last.len() - 1] != "." {
+        return Err(source.error(&last[last.len() - 2..], "the end of the trail"));
+    }
+    Ok(Grid::from_lines(&lines, |c| c))
+}
+
+pub fn part1(input: &Grid<char>) -> Result<Answer, String> {
+    let (start_node, end_node, graph) = construct_graph_bfs(input);
+    
+    // Pretty sure we might be able to do something like a modified dijkstra's algorithm here
+    // But in release mode this brute force thing it's reasonably fast (5s on my laptop for both parts)
+
+    let max = petgraph::algo::all_simple_paths(&graph, start_node, end_node, 0, None).map(|p: Vec<NodeIndex>| get_len(&graph, &p)).max();
+
+    max.map(Answer::from).ok_or_else(|| "there is no hike from the start to the end".to_string())
+}
+
+pub fn part2(input: &Grid<char>) -> Result<Answer, String> {
+    let (start_node, end_node, graph) = construct_graph_bfs2(input);
+    
+    let max = petgraph::algo::all_simple_paths(&graph, start_node, end_node, 0, None).map(|p: Vec<NodeIndex>| get_len(&graph, &p)).max();
+
+    max.map(Answer::from).ok_or_else(|| "there is no hike from the start to the end".to_string())
+}
+
+// The tiles from `from` to `to` through the longest corridor between them. Graph nodes
+// can also lie inside a corridor, so only tiles where paths fork end a corridor early.
+fn corridor(grid: &Grid<char>, from: Pos, to: Pos) -> Vec<Pos> {
+    let open = |p: Pos| grid.neighbors4(p).filter(|&n| grid[n] != '#');
+    let mut longest: Vec<Pos> = Vec::new();
+    for first in open(from) {
+        let mut tiles = vec![from, first];
+        loop {
+            let (current, previous) = (tiles[tiles.len() - 1], tiles[tiles.len() - 2]);
+            if current == to {
+                if tiles.len() > longest.len() {
+                    longest = tiles;
+                }
+                break;
+            }
+            let next: Vec<Pos> = open(current).filter(|&n| n != previous).collect();
+            if next.len() != 1 {
+                break;
+            }
+            tiles.push(next[0]);
+        }
+    }
+    longest
+}
+
+// The forest with the longest hike of `part`
+fn render(input: &Grid<char>, part: u8) -> Option<Scene> {
+    let (start_node, end_node, graph) = if part == 1 { construct_graph_bfs(input) } else { construct_graph_bfs2(input) };
+    let hike = petgraph::algo::all_simple_paths(&graph, start_node, end_node, 0, None)
+        .max_by_key(|p: &Vec<NodeIndex>| get_len(&graph, p))?;
+
+    let mut tiles = vec![graph[hike[0]]];
+    for pair in hike.windows(2) {
+        tiles.extend(corridor(input, graph[pair[0]], graph[pair[1]]).into_iter().skip(1));
+    }
+
+    let cells = input.map(|&c| match c {
+        '#' => Rgb(40, 100, 50),
+        '.' => Rgb::WHITE,
+        _ => Rgb::gray(200),
+    });
+    Some(Scene::grid(cells).path(tiles.into_iter().map(Scene::center), Rgb::RED))
+}
+
+/// A `size` by `size` maze (rounded up to odd) like the real inputs: a random maze of
+/// corridors between the odd tiles with `size / 4` walls knocked out to make loops, no
+/// dead ends, and slopes around every junction pointing away from the start
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let size = size.max(5) | 1;
+    let cells = (size - 1) / 2;
+    let cell = |row: usize, col: usize| Pos::new(2 * row + 1, 2 * col + 1);
+    let between = |a: Pos, b: Pos| Pos::new((a.row + b.row) / 2, (a.col + b.col) / 2);
+    let mut maze = Grid::new(size, size, '#');
+    let mut seen = Grid::new(cells, cells, false);
+
+    // A depth first maze, every cell reachable in exactly one way
+    let mut stack = vec![Pos::new(0, 0)];
+    seen[Pos::new(0, 0)] = true;
+    maze[cell(0, 0)] = '.';
+    while let Some(&current) = stack.last() {
+        let unseen: Vec<Pos> = seen.neighbors4(current).filter(|&n| !seen[n]).collect();
+        if unseen.is_empty() {
+            stack.pop();
+            continue;
+        }
+        let next = *rng.pick(&unseen);
+        seen[next] = true;
+        let (from, to) = (cell(current.row, current.col), cell(next.row, next.col));
+        maze[to] = '.';
+        maze[between(from, to)] = '.';
+        stack.push(next);
+    }
+    let mut walls: Vec<Pos> = maze.positions().filter(|p| (p.row + p.col) % 2 == 1 && maze[*p] == '#').collect();
+    walls.retain(|p| p.row > 0 && p.col > 0 && p.row < size - 1 && p.col < size - 1);
+    rng.shuffle(&mut walls);
+    for &wall in walls.iter().take((size / 4).max(1)) {
+        maze[wall] = '.';
+    }
+    let (start, end) = (Pos::new(0, 1), Pos::new(size - 1, size - 2));
+    maze[start] = '.';
+    maze[end] = '.';
+
+    // Walls up the dead ends until only the loops and the way between them remain
+    let mut pruned = true;
+    while pruned {
+        pruned = false;
+        for pos in maze.positions() {
+            if maze[pos] == '.' && pos != start && pos != end && maze.neighbors4(pos).filter(|&n| maze[n] != '#').count() <= 1 {
+                maze[pos] = '#';
+                pruned = true;
+            }
+        }
+    }
+
+    let mut distances = Grid::new(size, size, usize::MAX);
+    let mut queue = VecDeque::from([(start, 0)]);
+    while let Some((pos, distance)) = queue.pop_front() {
+        if maze[pos] == '#' || distances[pos] != usize::MAX {
+            continue;
+        }
+        distances[pos] = distance;
+        queue.extend(maze.neighbors4(pos).map(|n| (n, distance + 1)));
+    }
+    let arrow = |direction| match direction {
+        Direction::Up => '^',
+        Direction::Down => 'v',
+        Direction::Left => '<',
+        Direction::Right => '>',
+    };
+    for junction in maze.positions().filter(|&p| maze[p] != '#' && maze.neighbors4(p).filter(|&n| maze[n] != '#').count() > 2).collect::<Vec<_>>() {
+        for direction in Direction::ALL {
+            let Some(next) = maze.step(junction, direction).filter(|&n| maze[n] != '#' && n != start && n != end) else {
+                continue;
+            };
+            maze[next] = arrow(if distances[next] > distances[junction] { direction } else { direction.opposite() });
+        }
+    }
+    maze.to_string()
+}
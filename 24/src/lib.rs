@@ -0,0 +1,251 @@
+use std::ops::RangeInclusive;
+
+use nalgebra::Vector3;
+use num::{BigInt, Signed, ToPrimitive, Zero};
+
+use aoc_common::{Answer, Param, Params, ParseError, Rng, Solution, Source};
+
+// Part 2 is solved as an exact linear system by default. The z3 solver it started out
+// with needs libz3 and libclang to build, so it is only used with the `z3` feature.
+#[cfg(not(feature = "z3"))]
+mod linear;
+#[cfg(feature = "z3")]
+mod smt;
+#[cfg(not(feature = "z3"))]
+use linear::find_rock;
+#[cfg(feature = "z3")]
+use smt::find_rock;
+
+pub struct Day24;
+
+impl Solution for Day24 {
+    type Input = Vec<Hailstone>;
+
+    const PARAMS: &'static [Param] = &[AREA_MIN, AREA_MAX];
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse(input)
+    }
+
+    fn part1(input: &Self::Input, params: &Params) -> Result<Answer, String> {
+        Ok(part1(input, params.get(&AREA_MIN) as f64..=params.get(&AREA_MAX) as f64))
+    }
+
+    fn part2(input: &Self::Input, _params: &Params) -> Result<Answer, String> {
+        part2(input)
+    }
+
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        generate(rng, size)
+    }
+}
+
+const AREA_MIN: Param = Param { name: "area_min", default: 200000000000000, help: "lowest x and y of the test area (part 1)" };
+const AREA_MAX: Param = Param { name: "area_max", default: 400000000000000, help: "highest x and y of the test area (part 1)" };
+
+// Position and velocity
+pub type Hailstone = (Vector3<f64>, Vector3<f64>);
+
+fn parse_vector(source: &Source, s: &str) -> Result<Vector3<f64>, ParseError> {
+    let (x, rest) = source.split_once(s, ",")?;
+    let (y, z) = source.split_once(rest, ",")?;
+    let x = source.number::<i64>(x.trim())?;
+    let y = source.number::<i64>(y.trim())?;
+    let z = source.number::<i64>(z.trim())?;
+    Ok(Vector3::new(x as f64, y as f64, z as f64))
+}
+
+fn parse_line(source: &Source, line: &str) -> Result<Hailstone, ParseError> {
+    let (pos, vel) = source.split_once(line, " @ ")?;
+    Ok((parse_vector(source, pos)?, parse_vector(source, vel)?))
+}
+
+// The hailstones cross where p1 + s * v1 = p2 + t * v2 for times s and t after 0. Worked
+// out in whole numbers, the positions are too far out for f64 to find the crossings of
+// almost parallel paths.
+fn check_intersection(pos1: &Vector3<f64>, vel1: &Vector3<f64>, pos2: &Vector3<f64>, vel2: &Vector3<f64>) -> Option<Vector3<f64>> {
+    let [p1, v1, p2, v2] = [pos1, vel1, pos2, vel2].map(integers);
+    let cross = |a: &[BigInt; 3], b: &[BigInt; 3]| [0, 1, 2].map(|i| &a[(i + 1) % 3] * &b[(i + 2) % 3] - &a[(i + 2) % 3] * &b[(i + 1) % 3]);
+    let dot = |a: &[BigInt; 3], b: &[BigInt; 3]| (0..3).map(|i| &a[i] * &b[i]).sum::<BigInt>();
+
+    let normal = cross(&v1, &v2);
+    let delta = [0, 1, 2].map(|i| &p2[i] - &p1[i]);
+    // Parallel paths, or ones that pass each other at different heights
+    if normal.iter().all(Zero::is_zero) || !dot(&delta, &normal).is_zero() {
+        return None;
+    }
+
+    // Both times are these over normal . normal
+    let s = dot(&cross(&delta, &v2), &normal);
+    let t = dot(&cross(&delta, &v1), &normal);
+    if !s.is_positive() || !t.is_positive() {
+        return None;
+    }
+    let s = s.to_f64()? / dot(&normal, &normal).to_f64()?;
+    Some(pos1 + s * vel1)
+}
+
+
+fn remove_z(v: &Vector3<f64>) -> Vector3<f64> {
+    Vector3::new(v.x, v.y, 0.0)
+}
+
+
+pub fn parse(input_str: &str) -> Result<Vec<Hailstone>, ParseError> {
+    let source = Source::new(input_str);
+    input_str.lines().filter(|l| !l.is_empty()).map(|l| parse_line(&source, l)).collect::<Result<Vec<_>, _>>()
+}
+
+pub fn part1(input: &[Hailstone], range: RangeInclusive<f64>) -> Answer {
+    // for each combination of two lines check if they intersect within the range
+    let mut result = 0;
+    for i in 0..input.len() {
+        for j in i+1..input.len() {
+            let (pos1, vel1) = input[i];
+            let (pos2, vel2) = input[j];
+
+            if let Some(p) = check_intersection(&remove_z(&pos1), &remove_z(&vel1), &remove_z(&pos2), &remove_z(&vel2)) {
+                if range.contains(&p.x) && range.contains(&p.y) {
+                    result += 1;
+                }
+            }
+        }
+    }
+
+    result.into()
+}
+
+/// A rock thrown from `position` at `velocity`, in whole numbers
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Rock {
+    pub position: [BigInt; 3],
+    pub velocity: [BigInt; 3],
+}
+
+impl Rock {
+    /// Whether the rock and `hailstone` are at the same place at some time from 0 on
+    pub fn hits(&self, hailstone: &Hailstone) -> bool {
+        let (p, v) = (integers(&hailstone.0), integers(&hailstone.1));
+        // The rock catches up by v - velocity every nanosecond, that has to point the
+        // same way as the distance position - p
+        let distance: Vec<BigInt> = (0..3).map(|i| &self.position[i] - &p[i]).collect();
+        let closing: Vec<BigInt> = (0..3).map(|i| &v[i] - &self.velocity[i]).collect();
+        if closing.iter().all(Zero::is_zero) {
+            return distance.iter().all(Zero::is_zero);
+        }
+        let parallel = (0..3).all(|i| (&distance[(i + 1) % 3] * &closing[(i + 2) % 3] - &distance[(i + 2) % 3] * &closing[(i + 1) % 3]).is_zero());
+        let dot: BigInt = (0..3).map(|i| &distance[i] * &closing[i]).sum();
+        parallel && !dot.is_negative()
+    }
+}
+
+fn integers(v: &Vector3<f64>) -> [BigInt; 3] {
+    [v.x as i64, v.y as i64, v.z as i64].map(BigInt::from)
+}
+
+pub fn part2(input: &[Hailstone]) -> Result<Answer, String> {
+    /*
+        Equation system for part 2:
+        p + t1 * v = p1 + t1 * v1
+        p + t2 * v = p2 + t2 * v2
+        p + t3 * v = p3 + t3 * v3
+        ...
+     */
+
+    let rock = find_rock(input).ok_or("no rock hits every hailstone")?;
+    if let Some(i) = input.iter().position(|hailstone| !rock.hits(hailstone)) {
+        return Err(format!("the rock the solver found misses hailstone {}", i + 1));
+    }
+    Ok(rock.position.iter().sum::<BigInt>().into())
+}
+
+/// `size` hailstones (at least 3) that a rock thrown from around the middle of the test
+/// area hits at different whole nanoseconds, so part 2 has a solution in whole numbers
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let rock = [0; 3].map(|_| rng.range(250_000_000_000_000..=350_000_000_000_000));
+    let rock_velocity = [0; 3].map(|_| rng.range(-300..=300));
+    let mut times = Vec::new();
+    let mut lines = Vec::new();
+    while lines.len() < size.max(3) {
+        let time = rng.range(10_000_000_000..=1_000_000_000_000);
+        let velocity = [0; 3].map(|_| rng.range(-300..=300));
+        if times.contains(&time) || velocity == rock_velocity {
+            continue;
+        }
+        times.push(time);
+        let position: Vec<i64> = (0..3).map(|i| rock[i] + time * (rock_velocity[i] - velocity[i])).collect();
+        lines.push(format!(
+            "{}, {}, {} @ {}, {}, {}",
+            position[0], position[1], position[2], velocity[0], velocity[1], velocity[2]
+        ));
+    }
+    lines.join("\n") + "\n"
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use nalgebra::{Point2, Vector2};
+    use proptest::prelude::*;
+
+    // The crossing in the plane, what the 3D check is compared against
+    fn intersection_point(line1_origin: Point2<f64>, line1_direction: Vector2<f64>, line2_origin: Point2<f64>, line2_direction: Vector2<f64>) -> Option<Point2<f64>> {
+        let delta = line2_origin - line1_origin;
+
+        let det = line2_direction.x * line1_direction.y - line2_direction.y * line1_direction.x;
+
+        if det.abs() < 1e-10 {
+            // Lines are parallel or coincident
+            return None;
+        }
+
+        let s = (line2_direction.x * delta.y - line2_direction.y * delta.x) / det;
+        let t = (line1_direction.x * delta.y - line1_direction.y * delta.x) / det;
+
+        // check whether both are positive
+
+        if s < 0.0 || t < 0.0 {
+            return None;
+        }
+
+        Some(line1_origin + s * line1_direction)
+    }
+
+    #[test]
+    fn rocks_hit_every_hailstone() {
+        let input = generate(&mut aoc_common::Rng::new(1), 8);
+        let hailstones = parse(&input).unwrap();
+        let answer = part2(&hailstones).unwrap();
+        let rock = find_rock(&hailstones).unwrap();
+        assert_eq!(answer, Answer::from(rock.position.iter().sum::<BigInt>()));
+        assert!(hailstones.iter().all(|h| rock.hits(h)));
+
+        // Moving a hailstone off the path of the rock leaves no rock that hits them all
+        let (first, rest) = input.split_once(',').unwrap();
+        let moved = format!("{}{},{}", first, 1, rest);
+        assert_eq!(part2(&parse(&moved).unwrap()), Err("no rock hits every hailstone".to_string()));
+    }
+
+    // Scaled like the real inputs
+    fn hailstone() -> impl Strategy<Value = (Point2<f64>, Vector2<f64>)> {
+        let coordinate = 100_000_000_000_000..=500_000_000_000_000i64;
+        let speed = -1000..=1000i64;
+        (coordinate.clone(), coordinate, speed.clone(), speed)
+            .prop_filter("hailstones move", |(_, _, vx, vy)| (*vx, *vy) != (0, 0))
+            .prop_map(|(x, y, vx, vy)| (Point2::new(x as f64, y as f64), Vector2::new(vx as f64, vy as f64)))
+    }
+
+    proptest! {
+        #[test]
+        fn crossings_agree_in_2d_and_3d((p1, v1) in hailstone(), (p2, v2) in hailstone()) {
+            let flat = |v: Vector2<f64>| Vector3::new(v.x, v.y, 0.0);
+            let in_3d = check_intersection(&flat(p1.coords), &flat(v1), &flat(p2.coords), &flat(v2));
+            let in_2d = intersection_point(p1, v1, p2, v2);
+            match (in_3d, in_2d) {
+                (None, None) => {}
+                (Some(a), Some(b)) => prop_assert!((a.xy() - b.coords).norm() < 1e-9 * b.coords.norm(), "{} != {}", a.xy(), b),
+                (a, b) => prop_assert!(false, "3d: {:?}, 2d: {:?}", a, b),
+            }
+        }
+    }
+}
@@ -0,0 +1,67 @@
+use num::{BigInt, BigRational, Zero};
+
+use crate::{integers, Hailstone, Rock};
+
+// Since p - pi and v - vi have to be parallel for every hailstone, (p - pi) x (v - vi) = 0.
+// The p x v term is the same for all hailstones, so subtracting the equations of two hailstones
+// i and j leaves a linear system: p x (vj - vi) + (pj - pi) x v = pj x vj - pi x vi.
+// Two such pairs give six equations for the six unknowns, which we solve exactly. A
+// solution only counts if it is whole and hits every hailstone, not just the three of
+// the equations.
+pub fn find_rock(input: &[Hailstone]) -> Option<Rock> {
+    let cross = |a: &[BigInt; 3], b: &[BigInt; 3]| [
+        &a[1] * &b[2] - &a[2] * &b[1],
+        &a[2] * &b[0] - &a[0] * &b[2],
+        &a[0] * &b[1] - &a[1] * &b[0],
+    ];
+    let hail = input.iter().map(|(p, v)| (integers(p), integers(v))).collect::<Vec<_>>();
+
+    // Some triples might be degenerate (e.g. parallel hailstones), so just try the next ones
+    for i in 0..hail.len().saturating_sub(2) {
+        let mut rows = Vec::new();
+        for j in [i + 1, i + 2] {
+            let (pi, vi) = &hail[i];
+            let (pj, vj) = &hail[j];
+            let a = [&vj[0] - &vi[0], &vj[1] - &vi[1], &vj[2] - &vi[2]];
+            let b = [&pj[0] - &pi[0], &pj[1] - &pi[1], &pj[2] - &pi[2]];
+            let ci = cross(pi, vi);
+            let cj = cross(pj, vj);
+            let c = [&cj[0] - &ci[0], &cj[1] - &ci[1], &cj[2] - &ci[2]];
+            let zero = BigInt::zero();
+            rows.push([zero.clone(), a[2].clone(), -&a[1], zero.clone(), -&b[2], b[1].clone(), c[0].clone()]);
+            rows.push([-&a[2], zero.clone(), a[0].clone(), b[2].clone(), zero.clone(), -&b[0], c[1].clone()]);
+            rows.push([a[1].clone(), -&a[0], zero.clone(), -&b[1], b[0].clone(), zero, c[2].clone()]);
+        }
+        let rows = rows.into_iter()
+            .map(|r| r.map(BigRational::from_integer))
+            .collect::<Vec<_>>();
+        let Some(solution) = solve_linear(rows) else { continue };
+        if solution.iter().all(|x| x.is_integer()) {
+            let value = |i: usize| solution[i].to_integer();
+            let rock = Rock { position: [value(0), value(1), value(2)], velocity: [value(3), value(4), value(5)] };
+            if input.iter().all(|hailstone| rock.hits(hailstone)) {
+                return Some(rock);
+            }
+        }
+    }
+    None
+}
+
+// Gaussian elimination on an augmented matrix, None if the system is singular
+fn solve_linear(mut rows: Vec<[BigRational; 7]>) -> Option<Vec<BigRational>> {
+    let n = rows.len();
+    for col in 0..n {
+        let pivot = (col..n).find(|&r| !rows[r][col].is_zero())?;
+        rows.swap(col, pivot);
+        let pivot_row = rows[col].clone();
+        for (r, row) in rows.iter_mut().enumerate() {
+            if r != col && !row[col].is_zero() {
+                let factor = &row[col] / &pivot_row[col];
+                for k in col..=n {
+                    row[k] = &row[k] - &factor * &pivot_row[k];
+                }
+            }
+        }
+    }
+    Some((0..n).map(|r| &rows[r][n] / &rows[r][r]).collect())
+}
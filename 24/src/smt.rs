@@ -0,0 +1,48 @@
+use num::BigInt;
+use z3::{Solver, SatResult, Config, Context, ast::{self, Ast}};
+
+use crate::{Hailstone, Rock};
+
+pub fn find_rock(input: &[Hailstone]) -> Option<Rock> {
+    // I tried but failed to solve this underdetermined equation system by myself so i gave up and just used z3...
+
+    // Create Z3 context
+    let cfg = Config::new();
+    let ctx = Context::new(&cfg);
+    let solver = Solver::new(&ctx);
+
+    // Variables for the line to be found
+    let p0_x = ast::Real::new_const(&ctx, "p0_x");
+    let p0_y = ast::Real::new_const(&ctx, "p0_y");
+    let p0_z = ast::Real::new_const(&ctx, "p0_z");
+    let v_x = ast::Real::new_const(&ctx, "v_x");
+    let v_y = ast::Real::new_const(&ctx, "v_y");
+    let v_z = ast::Real::new_const(&ctx, "v_z");
+
+
+    // Formulate equations
+    for (i, (p, v)) in input.iter().enumerate() {
+        let tn = ast::Real::new_const(&ctx, format!("t{}", i));
+        let px = ast::Real::from_real_str(&ctx, &p.x.to_string(), &1.to_string()).unwrap();
+        let py = ast::Real::from_real_str(&ctx, &p.y.to_string(), &1.to_string()).unwrap();
+        let pz = ast::Real::from_real_str(&ctx, &p.z.to_string(), &1.to_string()).unwrap();
+        let vx = ast::Real::from_real_str(&ctx, &v.x.to_string(), &1.to_string()).unwrap();
+        let vy = ast::Real::from_real_str(&ctx, &v.y.to_string(), &1.to_string()).unwrap();
+        let vz = ast::Real::from_real_str(&ctx, &v.z.to_string(), &1.to_string()).unwrap();
+        solver.assert(&(&p0_x + &tn * &v_x)._eq(&(&px + &tn * &vx)));
+        solver.assert(&(&p0_y + &tn * &v_y)._eq(&(&py + &tn * &vy)));
+        solver.assert(&(&p0_z + &tn * &v_z)._eq(&(&pz + &tn * &vz)));
+    }
+
+    // Check satisfiability, the rock has to be whole
+    if solver.check() == SatResult::Sat {
+        let model = solver.get_model()?;
+        let value = |x: &ast::Real| model.eval(x, true).and_then(|x| x.as_real()).filter(|&(_, denominator)| denominator == 1).map(|(numerator, _)| BigInt::from(numerator));
+        Some(Rock {
+            position: [value(&p0_x)?, value(&p0_y)?, value(&p0_z)?],
+            velocity: [value(&v_x)?, value(&v_y)?, value(&v_z)?],
+        })
+    } else {
+        None
+    }
+}
@@ -0,0 +1,151 @@
+use std::collections::{BTreeMap, BTreeSet};
+
+use aoc_common::{Answer, Params, ParseError, Rng, Solution, Source};
+
+pub struct Day25;
+
+impl Solution for Day25 {
+    type Input = BTreeMap<String, Vec<String>>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse(input)
+    }
+
+    fn part1(input: &Self::Input, _params: &Params) -> Result<Answer, String> {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input, _params: &Params) -> Result<Answer, String> {
+        Ok(part2(input))
+    }
+
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        generate(rng, size)
+    }
+}
+
+fn parse_line(source: &Source, adj_list: &mut BTreeMap<String, Vec<String>>, line: &str) -> Result<(), ParseError> {
+    let (left, right_str) = source.split_once(line, ": ")?;
+    let right = right_str.split(" ").map(|s| s.to_string()).collect::<Vec<_>>();
+
+    adj_list.entry(left.to_string()).or_default().extend_from_slice(&right);
+
+    for right in right {
+        adj_list.entry(right).or_default().push(left.to_string());
+    }
+    Ok(())
+}
+
+#[allow(clippy::needless_range_loop)]
+fn stoer_wagner(adj_list: BTreeMap<String, Vec<String>>) -> (i32, Vec<usize>) {
+    let mut mat = vec![vec![0; adj_list.len()]; adj_list.len()];
+    for (i, (_, value)) in adj_list.iter().enumerate() {
+        for v in value {
+            let value_index = adj_list.iter().position(|(k, _)| k == v).unwrap();
+            mat[i][value_index] = 1;
+        }
+    }
+
+    let mut best = (i32::MAX, vec![]);
+    let n = mat.len();
+    let mut co: Vec<Vec<usize>> = (0..n).map(|i| vec![i]).collect();
+
+    for ph in 1..n {
+        let mut w = mat[0].clone();
+        let mut s = 0;
+        let mut t = 0;
+        for _ in 0..n - ph {
+            w[t] = i32::MIN;
+            s = t;
+            t = w.iter().enumerate().max_by_key(|(_, v)| *v).unwrap().0;
+            for i in 0..n {
+                w[i] += mat[t][i];
+            }
+        }
+        best = std::cmp::min(best, (w[t] - mat[t][t], co[t].clone()));
+        let co_t = co[t].clone();
+        co[s].extend_from_slice(&co_t);
+        for i in 0..n {
+            mat[s][i] += mat[t][i];
+        }
+        for i in 0..n {
+            mat[i][s] = mat[s][i];
+        }
+        mat[0][t] = i32::MIN;
+    }
+
+    (best.0, best.1)
+}
+
+
+
+pub fn parse(input_str: &str) -> Result<BTreeMap<String, Vec<String>>, ParseError> {
+    let source = Source::new(input_str);
+    let mut adj_list = BTreeMap::new();
+    for line in input_str.lines().filter(|line| !line.is_empty()) {
+        parse_line(&source, &mut adj_list, line)?;
+    }
+    Ok(adj_list)
+}
+
+pub fn part1(adj_list: &BTreeMap<String, Vec<String>>) -> Result<Answer, String> {
+    if adj_list.len() < 2 {
+        return Err("there are no two groups of components to split".to_string());
+    }
+    let (min_cut, min_cut_vertices) = stoer_wagner(adj_list.clone());
+
+    if min_cut != 3 {
+        return Err(format!("the groups of components are connected by {} wires, not 3", min_cut));
+    }
+
+    Ok((min_cut_vertices.len() * (adj_list.len() - min_cut_vertices.len())).into())
+}
+
+// There is no second puzzle on the last day
+pub fn part2(_adj_list: &BTreeMap<String, Vec<String>>) -> Answer {
+    "Merry Christmas!".into()
+}
+
+/// Two halves of `size` components (at least 10 in all) wired by exactly three wires.
+/// Each half is a ring where every component also connects to the one after next, which
+/// takes four cuts to split, plus some random extra wires.
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let size = size.max(10);
+    let mut names: Vec<String> = Vec::new();
+    while names.len() < size {
+        let name: String = (0..3).map(|_| (b'a' + rng.below(26) as u8) as char).collect();
+        if !names.contains(&name) {
+            names.push(name);
+        }
+    }
+    let halves = [(0, size / 2), (size / 2, size - size / 2)];
+    let mut wires = Vec::new();
+    for (first, count) in halves {
+        for i in 0..count {
+            wires.push((first + i, first + (i + 1) % count));
+            wires.push((first + i, first + (i + 2) % count));
+        }
+        for _ in 0..count / 2 {
+            let (a, b) = (first + rng.below(count), first + rng.below(count));
+            wires.push((a, b));
+        }
+    }
+    let mut cut = Vec::new();
+    while cut.len() < 3 {
+        let wire = (rng.below(size / 2), size / 2 + rng.below(size - size / 2));
+        if !cut.contains(&wire) {
+            cut.push(wire);
+        }
+    }
+    wires.extend(cut);
+
+    let mut connections: BTreeMap<&str, Vec<&str>> = BTreeMap::new();
+    let mut seen = BTreeSet::new();
+    for (a, b) in wires {
+        if a != b && seen.insert((a.min(b), a.max(b))) {
+            let (from, to) = if rng.chance(0.5) { (a, b) } else { (b, a) };
+            connections.entry(&names[from]).or_default().push(&names[to]);
+        }
+    }
+    connections.iter().map(|(from, to)| format!("{}: {}\n", from, to.join(" "))).collect()
+}
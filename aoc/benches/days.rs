@@ -0,0 +1,27 @@
+use std::{fs::read_to_string, hint::black_box, path::Path};
+
+use aoc::{bench, days};
+use criterion::{criterion_group, criterion_main, Criterion};
+
+// Same inputs as `aoc bench`: the real input if it is there, the demo otherwise
+fn days(c: &mut Criterion) {
+    let root = Path::new(env!("CARGO_MANIFEST_DIR")).join("..");
+    for day in 1..=25 {
+        let input = bench::input(&root, day);
+        let text = read_to_string(&input.path).unwrap();
+        let parse = days::get(day);
+        let parsed = parse(&text).unwrap();
+
+        let mut group = c.benchmark_group(format!("day {:02}", day));
+        // Some parts take seconds on the real input
+        group.sample_size(10);
+        group.bench_function("parse", |b| b.iter(|| parse(black_box(&text))));
+        for &part in input.parts.iter() {
+            group.bench_function(format!("part {}", part), |b| b.iter(|| parsed.part(part, &input.params)));
+        }
+        group.finish();
+    }
+}
+
+criterion_group!(benches, days);
+criterion_main!(benches);
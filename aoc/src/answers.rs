@@ -0,0 +1,297 @@
+use std::{
+    collections::BTreeMap,
+    fmt::{self, Display},
+    fs::{read_to_string, write},
+    path::{Path, PathBuf},
+};
+
+use aoc_common::Answer;
+use clap::ValueEnum;
+
+/// What the puzzle page said about a submitted answer
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum Feedback {
+    Correct,
+    Wrong,
+    TooHigh,
+    TooLow,
+}
+
+/// Everything known about the answer of one part
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Record {
+    pub correct: Option<String>,
+    /// Rejected answers, including the ones that were too high or too low
+    pub wrong: Vec<String>,
+    /// The lowest answer rejected as too high
+    pub too_high: Option<i128>,
+    /// The highest answer rejected as too low
+    pub too_low: Option<i128>,
+}
+
+/// How a computed answer compares to the record
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Verdict {
+    Confirmed,
+    /// Differs from the confirmed answer
+    Mismatch(String),
+    KnownWrong,
+    TooHigh(i128),
+    TooLow(i128),
+    Unknown,
+}
+
+impl Verdict {
+    pub fn name(&self) -> &'static str {
+        match self {
+            Verdict::Confirmed => "confirmed",
+            Verdict::Mismatch(_) => "mismatch",
+            Verdict::KnownWrong => "known_wrong",
+            Verdict::TooHigh(_) => "too_high",
+            Verdict::TooLow(_) => "too_low",
+            Verdict::Unknown => "unknown",
+        }
+    }
+}
+
+impl Display for Verdict {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Verdict::Confirmed => write!(f, "confirmed"),
+            Verdict::Mismatch(correct) => write!(f, "wrong, the correct answer is {}", correct),
+            Verdict::KnownWrong => write!(f, "wrong, was rejected before"),
+            Verdict::TooHigh(bound) => write!(f, "too high, {} already was", bound),
+            Verdict::TooLow(bound) => write!(f, "too low, {} already was", bound),
+            Verdict::Unknown => write!(f, "not verified"),
+        }
+    }
+}
+
+impl Record {
+    pub fn check(&self, answer: &str) -> Verdict {
+        if let Some(correct) = &self.correct {
+            return if correct == answer { Verdict::Confirmed } else { Verdict::Mismatch(correct.clone()) };
+        }
+        if let Ok(n) = answer.parse::<i128>() {
+            match (self.too_high, self.too_low) {
+                (Some(high), _) if n >= high => return Verdict::TooHigh(high),
+                (_, Some(low)) if n <= low => return Verdict::TooLow(low),
+                _ => {}
+            }
+        }
+        if self.wrong.iter().any(|w| w == answer) {
+            return Verdict::KnownWrong;
+        }
+        Verdict::Unknown
+    }
+
+    pub fn record(&mut self, answer: &str, feedback: Feedback) -> Result<(), String> {
+        if feedback == Feedback::Correct {
+            self.correct = Some(answer.to_string());
+            return Ok(());
+        }
+
+        if !self.wrong.iter().any(|w| w == answer) {
+            self.wrong.push(answer.to_string());
+        }
+        let bound = || answer.parse::<i128>().map_err(|_| format!("{} is not a number, it can't be too high or too low", answer));
+        match feedback {
+            Feedback::TooHigh => {
+                let n = bound()?;
+                self.too_high = Some(self.too_high.map_or(n, |h| h.min(n)));
+            }
+            Feedback::TooLow => {
+                let n = bound()?;
+                self.too_low = Some(self.too_low.map_or(n, |l| l.max(n)));
+            }
+            _ => {}
+        }
+        Ok(())
+    }
+}
+
+/// The known answers of the real inputs, kept in `answers.toml` in the workspace root.
+///
+/// A part whose answer is all that's known is a plain value, anything else is a table:
+///
+///   [01]
+///   part1 = 54573
+///
+///   [21.part2]
+///   wrong = [600336060511101]
+///   too_low = 600336060511101
+///   too_high = 700000000000000
+///
+/// Saving rewrites the whole file, comments are not kept.
+pub struct Store {
+    path: PathBuf,
+    records: BTreeMap<(u8, u8), Record>,
+}
+
+fn value_to_string(value: &toml::Value) -> Option<String> {
+    match value {
+        toml::Value::String(s) => Some(s.clone()),
+        toml::Value::Integer(n) => Some(n.to_string()),
+        _ => None,
+    }
+}
+
+fn string_to_value(s: &str) -> toml::Value {
+    match s.parse::<i64>() {
+        Ok(n) => toml::Value::Integer(n),
+        Err(_) => toml::Value::String(s.to_string()),
+    }
+}
+
+// Bounds bigger than an i64 are stored as strings
+fn parse_bound(value: &toml::Value) -> Option<i128> {
+    value_to_string(value)?.parse().ok()
+}
+
+fn parse_record(value: &toml::Value) -> Result<Record, String> {
+    let table = match value {
+        toml::Value::Table(table) => table,
+        other => {
+            let correct = value_to_string(other).ok_or_else(|| format!("unexpected answer {}", other))?;
+            return Ok(Record { correct: Some(correct), ..Record::default() });
+        }
+    };
+
+    let mut record = Record::default();
+    for (key, value) in table {
+        match key.as_str() {
+            "answer" => record.correct = value_to_string(value),
+            "wrong" => {
+                let wrong = value.as_array().ok_or("`wrong` has to be a list")?;
+                record.wrong = wrong.iter().filter_map(value_to_string).collect();
+            }
+            "too_high" => record.too_high = parse_bound(value),
+            "too_low" => record.too_low = parse_bound(value),
+            other => return Err(format!("unknown key `{}`", other)),
+        }
+    }
+    Ok(record)
+}
+
+fn record_to_value(record: &Record) -> toml::Value {
+    if record.wrong.is_empty() && record.too_high.is_none() && record.too_low.is_none() {
+        if let Some(correct) = &record.correct {
+            return string_to_value(correct);
+        }
+    }
+
+    let bound = |n: i128| string_to_value(&n.to_string());
+    let mut table = toml::Table::new();
+    if let Some(correct) = &record.correct {
+        table.insert("answer".to_string(), string_to_value(correct));
+    }
+    if !record.wrong.is_empty() {
+        table.insert("wrong".to_string(), toml::Value::Array(record.wrong.iter().map(|w| string_to_value(w)).collect()));
+    }
+    if let Some(high) = record.too_high {
+        table.insert("too_high".to_string(), bound(high));
+    }
+    if let Some(low) = record.too_low {
+        table.insert("too_low".to_string(), bound(low));
+    }
+    toml::Value::Table(table)
+}
+
+impl Store {
+    /// An empty store if the file doesn't exist yet
+    pub fn load(path: &Path) -> Result<Store, String> {
+        let mut store = Store { path: path.to_path_buf(), records: BTreeMap::new() };
+        let text = match read_to_string(path) {
+            Ok(text) => text,
+            Err(_) if !path.exists() => return Ok(store),
+            Err(e) => return Err(format!("could not read {}: {}", path.display(), e)),
+        };
+
+        let error = |e: String| format!("{}: {}", path.display(), e);
+        let table: toml::Table = text.parse().map_err(|e: toml::de::Error| error(e.message().to_string()))?;
+        for (key, parts) in table.iter() {
+            let day: u8 = key.parse().ok().filter(|d| (1..=25).contains(d)).ok_or_else(|| error(format!("{} is not a day", key)))?;
+            let parts = parts.as_table().ok_or_else(|| error(format!("[{}] has to be a table", key)))?;
+            for (part_key, value) in parts {
+                let part = match part_key.as_str() {
+                    "part1" => 1,
+                    "part2" => 2,
+                    other => return Err(error(format!("unknown key `{}` for day {:02}", other, day))),
+                };
+                let record = parse_record(value).map_err(|e| error(format!("day {:02} part {}: {}", day, part, e)))?;
+                store.records.insert((day, part), record);
+            }
+        }
+        Ok(store)
+    }
+
+    pub fn save(&self) -> Result<(), String> {
+        let mut table = toml::Table::new();
+        for (&(day, part), record) in self.records.iter() {
+            let day_table = table.entry(format!("{:02}", day)).or_insert_with(|| toml::Value::Table(toml::Table::new()));
+            day_table.as_table_mut().unwrap().insert(format!("part{}", part), record_to_value(record));
+        }
+        let text = toml::to_string(&table).map_err(|e| e.to_string())?;
+        write(&self.path, text).map_err(|e| format!("could not write {}: {}", self.path.display(), e))
+    }
+
+    pub fn get(&self, day: u8, part: u8) -> Option<&Record> {
+        self.records.get(&(day, part))
+    }
+
+    /// Every part with a confirmed answer
+    pub fn confirmed(&self) -> impl Iterator<Item = (u8, u8, &str)> {
+        self.records
+            .iter()
+            .filter_map(|(&(day, part), record)| Some((day, part, record.correct.as_deref()?)))
+    }
+
+    pub fn check(&self, day: u8, part: u8, answer: &Answer) -> Verdict {
+        self.get(day, part).map_or(Verdict::Unknown, |record| record.check(&answer.to_string()))
+    }
+
+    pub fn record(&mut self, day: u8, part: u8, answer: &str, feedback: Feedback) -> Result<(), String> {
+        self.records.entry((day, part)).or_default().record(answer, feedback)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn verdicts() {
+        let mut record = Record::default();
+        record.record("150", Feedback::TooHigh).unwrap();
+        record.record("120", Feedback::TooHigh).unwrap();
+        record.record("80", Feedback::TooLow).unwrap();
+        record.record("99", Feedback::Wrong).unwrap();
+        assert_eq!(record.check("130"), Verdict::TooHigh(120));
+        assert_eq!(record.check("80"), Verdict::TooLow(80));
+        assert_eq!(record.check("99"), Verdict::KnownWrong);
+        assert_eq!(record.check("100"), Verdict::Unknown);
+        assert!(record.record("abc", Feedback::TooLow).is_err());
+
+        record.record("100", Feedback::Correct).unwrap();
+        assert_eq!(record.check("100"), Verdict::Confirmed);
+        assert_eq!(record.check("101"), Verdict::Mismatch("100".to_string()));
+    }
+
+    #[test]
+    fn round_trip() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("answers.toml");
+        std::fs::write(&path, "[01]\npart1 = 54573\npart2 = \"abc\"\n").unwrap();
+
+        let mut store = Store::load(&path).unwrap();
+        store.record(21, 2, "600336060511101", Feedback::TooLow).unwrap();
+        store.record(21, 2, "170141183460469231731687303715884105727", Feedback::TooHigh).unwrap();
+        store.save().unwrap();
+
+        let text = read_to_string(&path).unwrap();
+        assert!(text.contains("part1 = 54573"), "{}", text);
+        let loaded = Store::load(&path).unwrap();
+        assert_eq!(loaded.get(21, 2), store.get(21, 2));
+        assert_eq!(loaded.confirmed().collect::<Vec<_>>(), [(1, 1, "54573"), (1, 2, "abc")]);
+    }
+}
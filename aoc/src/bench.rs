@@ -0,0 +1,132 @@
+use std::{
+    collections::BTreeMap,
+    fs::read_to_string,
+    hint::black_box,
+    path::{Path, PathBuf},
+    time::{Duration, Instant},
+};
+
+use aoc_common::Params;
+
+use crate::{days, demos::{self, DEMOS}, fetch};
+
+/// Nanoseconds per day ("01") and stage ("parse", "part1", "part2"), this is also the
+/// layout of the saved baseline files
+pub type Timings = BTreeMap<String, BTreeMap<String, u64>>;
+
+/// What a day is benchmarked on
+pub struct BenchInput {
+    pub path: PathBuf,
+    pub parts: Vec<u8>,
+    pub params: Params,
+    pub is_demo: bool,
+}
+
+/// The real input when there is one (see `fetch::input_path`), otherwise the day's
+/// first demo file, restricted to the parts that work on it and with its parameters
+pub fn input(root: &Path, day: u8) -> BenchInput {
+    let real = fetch::input_path(root, day);
+    if real.exists() {
+        return BenchInput { path: real, parts: vec![1, 2], params: Params::new(), is_demo: false };
+    }
+
+    let (_, file, part1, part2, overrides) = DEMOS.iter().find(|demo| demo.0 == day).unwrap();
+    let parts = [(1, part1), (2, part2)]
+        .into_iter()
+        .filter(|(_, expected)| expected.is_some())
+        .map(|(part, _)| part)
+        .collect();
+    BenchInput { path: root.join(format!("{:02}", day)).join(file), parts, params: demos::params(overrides), is_demo: true }
+}
+
+/// Median of running `f` over and over for about `budget`, but at least once
+pub fn measure<T>(budget: Duration, mut f: impl FnMut() -> T) -> Duration {
+    let mut samples = Vec::new();
+    let start = Instant::now();
+    while samples.is_empty() || (start.elapsed() < budget && samples.len() < 10_000) {
+        let t = Instant::now();
+        black_box(f());
+        samples.push(t.elapsed());
+    }
+    samples.sort();
+    samples[samples.len() / 2]
+}
+
+/// Times parsing and the parts of one day, each stage gets `budget`
+pub fn run(day: u8, input: &BenchInput, budget: Duration) -> Result<BTreeMap<String, u64>, String> {
+    let text = read_to_string(&input.path)
+        .map_err(|e| format!("day {:02}: could not read {}: {}", day, input.path.display(), e))?;
+    let parse = days::get(day);
+    let parsed = parse(&text).map_err(|e| e.on_day(day).to_string())?;
+
+    let mut timings = BTreeMap::new();
+    timings.insert("parse".to_string(), measure(budget, || parse(&text)).as_nanos() as u64);
+    for &part in input.parts.iter() {
+        parsed.part(part, &input.params).map_err(|e| format!("day {:02} part {}: {}", day, part, e))?;
+        let time = measure(budget, || parsed.part(part, &input.params));
+        timings.insert(format!("part{}", part), time.as_nanos() as u64);
+    }
+    Ok(timings)
+}
+
+pub fn format_nanos(nanos: u64) -> String {
+    let nanos = nanos as f64;
+    if nanos < 1e3 {
+        format!("{:.0} ns", nanos)
+    } else if nanos < 1e6 {
+        format!("{:.1} µs", nanos / 1e3)
+    } else if nanos < 1e9 {
+        format!("{:.1} ms", nanos / 1e6)
+    } else {
+        format!("{:.2} s", nanos / 1e9)
+    }
+}
+
+/// One row per day, one column per stage. With a baseline every cell also shows the
+/// relative change, positive means slower.
+pub fn table(timings: &Timings, baseline: Option<&Timings>) -> String {
+    const STAGES: [&str; 3] = ["parse", "part1", "part2"];
+    let width = if baseline.is_some() { 22 } else { 12 };
+
+    let mut out = format!("{:<5}", "day");
+    for stage in STAGES {
+        out += &format!("{:>width$}", stage);
+    }
+    out.push('\n');
+
+    for (day, stages) in timings {
+        out += &format!("{:<5}", day);
+        for stage in STAGES {
+            let cell = match stages.get(stage) {
+                None => "-".to_string(),
+                Some(&nanos) => {
+                    let before = baseline.and_then(|b| b.get(day)).and_then(|s| s.get(stage));
+                    match before {
+                        Some(&before) if before > 0 => {
+                            let change = (nanos as f64 / before as f64 - 1.0) * 100.0;
+                            format!("{} ({:+.1}%)", format_nanos(nanos), change)
+                        }
+                        _ => format_nanos(nanos),
+                    }
+                }
+            };
+            out += &format!("{:>width$}", cell);
+        }
+        out.push('\n');
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn table_with_baseline() {
+        let timings: Timings = serde_json::from_str(r#"{"05": {"parse": 1500, "part1": 2000000}}"#).unwrap();
+        let baseline: Timings = serde_json::from_str(r#"{"05": {"parse": 1000}}"#).unwrap();
+        let table = table(&timings, Some(&baseline));
+        let row = table.lines().nth(1).unwrap();
+        assert_eq!(row.split_whitespace().collect::<Vec<_>>(), ["05", "1.5", "µs", "(+50.0%)", "2.0", "ms", "-"]);
+    }
+}
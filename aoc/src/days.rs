@@ -0,0 +1,102 @@
+use std::marker::PhantomData;
+
+use aoc_common::{Answer, Param, Params, ParseError, Recording, Rng, Scene, Solution};
+
+/// A day's parsed input, the parts can be run on it any number of times
+pub trait Parsed {
+    fn part1(&self, params: &Params) -> Result<Answer, String>;
+    fn part2(&self, params: &Params) -> Result<Answer, String>;
+    fn visualize(&self, params: &Params, recording: &mut Recording);
+    fn render(&self, params: &Params, part: u8) -> Option<Scene>;
+    fn explain(&self, params: &Params, part: u8) -> Option<String>;
+
+    fn part(&self, part: u8, params: &Params) -> Result<Answer, String> {
+        if part == 1 {
+            self.part1(params)
+        } else {
+            self.part2(params)
+        }
+    }
+}
+
+struct Input<S: Solution> {
+    input: S::Input,
+    day: PhantomData<S>,
+}
+
+impl<S: Solution> Parsed for Input<S> {
+    fn part1(&self, params: &Params) -> Result<Answer, String> {
+        S::part1(&self.input, params)
+    }
+
+    fn part2(&self, params: &Params) -> Result<Answer, String> {
+        S::part2(&self.input, params)
+    }
+
+    fn visualize(&self, params: &Params, recording: &mut Recording) {
+        S::visualize(&self.input, params, recording)
+    }
+
+    fn render(&self, params: &Params, part: u8) -> Option<Scene> {
+        S::render(&self.input, params, part)
+    }
+
+    fn explain(&self, params: &Params, part: u8) -> Option<String> {
+        S::explain(&self.input, params, part)
+    }
+}
+
+pub type Parse = fn(&str) -> Result<Box<dyn Parsed>, ParseError>;
+
+fn parse<S: Solution + 'static>(input: &str) -> Result<Box<dyn Parsed>, ParseError> {
+    Ok(Box::new(Input::<S> { input: S::parse(input)?, day: PhantomData }))
+}
+
+macro_rules! days {
+    ($($krate:ident::$day:ident),* $(,)?) => {
+        pub const DAYS: [Parse; 25] = [$(parse::<$krate::$day>),*];
+        /// The parameters each day declares, see `Solution::PARAMS`
+        pub const PARAMS: [&[Param]; 25] = [$(<$krate::$day as Solution>::PARAMS),*];
+        /// See `Solution::check_params`
+        pub const PARAM_CHECKS: [fn(&Params) -> Result<(), String>; 25] = [$(<$krate::$day as Solution>::check_params),*];
+        /// The input generators, see `Solution::generate`
+        pub const GENERATORS: [fn(&mut Rng, usize) -> String; 25] = [$(<$krate::$day as Solution>::generate),*];
+    };
+}
+
+days![
+    aoc_2023_01::Day01, aoc_2023_02::Day02, aoc_2023_03::Day03, aoc_2023_04::Day04, aoc_2023_05::Day05,
+    aoc_2023_06::Day06, aoc_2023_07::Day07, aoc_2023_08::Day08, aoc_2023_09::Day09, aoc_2023_10::Day10,
+    aoc_2023_11::Day11, aoc_2023_12::Day12, aoc_2023_13::Day13, aoc_2023_14::Day14, aoc_2023_15::Day15,
+    aoc_2023_16::Day16, aoc_2023_17::Day17, aoc_2023_18::Day18, aoc_2023_19::Day19, aoc_2023_20::Day20,
+    aoc_2023_21::Day21, aoc_2023_22::Day22, aoc_2023_23::Day23, aoc_2023_24::Day24, aoc_2023_25::Day25,
+];
+
+pub fn get(day: u8) -> Parse {
+    DAYS[day as usize - 1]
+}
+
+pub fn params(day: u8) -> &'static [Param] {
+    PARAMS[day as usize - 1]
+}
+
+/// Checks the names of the parameters and what the day says about their values
+pub fn check_params(day: u8, params: &Params) -> Result<(), String> {
+    params.check(self::params(day))?;
+    PARAM_CHECKS[day as usize - 1](params)
+}
+
+/// A random input for `day`, the same one every time for the same `seed` and `size`
+pub fn generate(day: u8, seed: u64, size: usize) -> String {
+    GENERATORS[day as usize - 1](&mut Rng::new(seed), size)
+}
+
+/// Parses the input once and runs the requested parts on it, the errors tell the day
+pub fn run(day: u8, input: &str, part: Option<u8>, params: &Params) -> Result<Vec<(u8, Answer)>, String> {
+    let parsed = get(day)(input).map_err(|e| e.on_day(day).to_string())?;
+    let parts = match part {
+        Some(part) => vec![part],
+        None => vec![1, 2],
+    };
+    parts.into_iter().map(|part| Ok((part, parsed.part(part, params).map_err(|e| format!("day {:02} part {}: {}", day, part, e))?))).collect()
+}
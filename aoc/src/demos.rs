@@ -0,0 +1,58 @@
+use aoc_common::Params;
+
+/// Day, demo file, expected part 1, expected part 2 and parameter overrides
+pub type Demo = (u8, &'static str, Option<&'static str>, Option<&'static str>, &'static [(&'static str, i64)]);
+
+/// The demo files shipped with each day. A part is left out when the file was not meant
+/// for it, e.g. day 20 has no `rx` module in its demos and day 21's part 2 relies on
+/// properties only the real input has. The overrides are the values the puzzle text uses
+/// for its examples.
+pub const DEMOS: &[Demo] = &[
+    (1, "demo.txt", Some("142"), None, &[]),
+    (1, "demo2.txt", None, Some("309"), &[]),
+    (2, "demo.txt", Some("8"), Some("2286"), &[]),
+    (3, "demo.txt", Some("4361"), Some("467835"), &[]),
+    (4, "demo.txt", Some("13"), Some("30"), &[]),
+    (5, "demo.txt", Some("35"), Some("46"), &[]),
+    (6, "demo.txt", Some("288"), Some("71503"), &[]),
+    (7, "demo.txt", Some("6440"), Some("5905"), &[]),
+    (8, "demo.txt", Some("2"), None, &[]),
+    (8, "demo2.txt", Some("6"), None, &[]),
+    (8, "demo3.txt", None, Some("6"), &[]),
+    (9, "demo.txt", Some("114"), Some("2"), &[]),
+    (10, "demo1.txt", Some("4"), None, &[]),
+    (10, "demo2.txt", Some("8"), None, &[]),
+    (10, "demo3.txt", None, Some("4"), &[]),
+    (10, "demo4.txt", None, Some("8"), &[]),
+    (10, "demo5.txt", None, Some("10"), &[]),
+    (11, "demo.txt", Some("374"), Some("82000210"), &[]),
+    (11, "demo.txt", None, Some("1030"), &[("factor", 10)]),
+    (11, "demo.txt", None, Some("8410"), &[("factor", 100)]),
+    (12, "demo.txt", Some("21"), Some("525152"), &[]),
+    (13, "demo.txt", Some("405"), Some("400"), &[]),
+    (14, "demo.txt", Some("136"), Some("64"), &[]),
+    (15, "demo.txt", Some("1320"), Some("145"), &[]),
+    (16, "demo.txt", Some("46"), Some("51"), &[]),
+    (17, "demo.txt", Some("102"), Some("94"), &[]),
+    (17, "demo2.txt", None, Some("71"), &[]),
+    (18, "demo.txt", Some("62"), Some("952408144115"), &[]),
+    (18, "demo2.txt", Some("40"), None, &[]),
+    (19, "demo.txt", Some("19114"), Some("167409079868000"), &[]),
+    (20, "demo.txt", Some("32000000"), None, &[]),
+    (20, "demo2.txt", Some("11687500"), None, &[]),
+    (21, "demo.txt", Some("2665"), None, &[]),
+    (21, "demo.txt", Some("16"), None, &[("steps", 6)]),
+    (22, "demo.txt", Some("5"), Some("7"), &[]),
+    (23, "demo.txt", Some("94"), Some("154"), &[]),
+    (24, "demo.txt", Some("2"), Some("47"), &[("area_min", 7), ("area_max", 27)]),
+    (25, "demo.txt", Some("54"), Some("Merry Christmas!"), &[]),
+];
+
+/// The parameters a demo is run with
+pub fn params(overrides: &[(&str, i64)]) -> Params {
+    let mut params = Params::new();
+    for &(name, value) in overrides {
+        params.set(name, value);
+    }
+    params
+}
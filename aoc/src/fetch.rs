@@ -0,0 +1,187 @@
+use std::{
+    env,
+    fs::{create_dir_all, read_to_string, rename, write},
+    path::{Path, PathBuf},
+    thread::sleep,
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+
+/// Settings of `aoc fetch`, read from `aoc-2023/config.toml` in the user's config
+/// directory or from the file `AOC_CONFIG` points to. The keys are `session`, `url`,
+/// `cache` and `min_interval` (in seconds), only the session token has no default.
+#[derive(Debug, Clone)]
+pub struct Config {
+    pub session: Option<String>,
+    pub url: String,
+    pub cache_dir: PathBuf,
+    pub min_interval: Duration,
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Config {
+            session: None,
+            url: "https://adventofcode.com".to_string(),
+            cache_dir: dirs::cache_dir().unwrap_or_else(env::temp_dir).join("aoc-2023"),
+            min_interval: Duration::from_secs(5),
+        }
+    }
+}
+
+impl Config {
+    pub fn path() -> Option<PathBuf> {
+        match env::var_os("AOC_CONFIG") {
+            Some(path) => Some(PathBuf::from(path)),
+            None => dirs::config_dir().map(|dir| dir.join("aoc-2023").join("config.toml")),
+        }
+    }
+
+    /// The defaults when there is no config file
+    pub fn load() -> Result<Config, String> {
+        let Some(path) = Config::path() else {
+            return Ok(Config::default());
+        };
+        match read_to_string(&path) {
+            Ok(text) => Config::parse(&text).map_err(|e| format!("{}: {}", path.display(), e)),
+            Err(_) if !path.exists() => Ok(Config::default()),
+            Err(e) => Err(format!("could not read {}: {}", path.display(), e)),
+        }
+    }
+
+    pub fn parse(text: &str) -> Result<Config, String> {
+        let table: toml::Table = text.parse().map_err(|e: toml::de::Error| e.message().to_string())?;
+        let mut config = Config::default();
+        for (key, value) in table {
+            match (key.as_str(), value) {
+                ("session", toml::Value::String(s)) => config.session = Some(s.trim().to_string()),
+                ("url", toml::Value::String(s)) => config.url = s.trim_end_matches('/').to_string(),
+                ("cache", toml::Value::String(s)) => config.cache_dir = PathBuf::from(s),
+                ("min_interval", toml::Value::Integer(n)) if n >= 0 => config.min_interval = Duration::from_secs(n as u64),
+                ("min_interval", toml::Value::Float(n)) if n >= 0.0 => config.min_interval = Duration::from_secs_f64(n),
+                (key @ ("session" | "url" | "cache" | "min_interval"), value) => {
+                    return Err(format!("invalid value {} for `{}`", value, key));
+                }
+                (key, _) => return Err(format!("unknown setting `{}`", key)),
+            }
+        }
+        Ok(config)
+    }
+}
+
+/// Where downloaded inputs are kept, one file per day
+pub struct Cache {
+    dir: PathBuf,
+}
+
+impl Cache {
+    pub fn new(dir: impl Into<PathBuf>) -> Self {
+        Cache { dir: dir.into() }
+    }
+
+    pub fn path(&self, day: u8) -> PathBuf {
+        self.dir.join(format!("{:02}.txt", day))
+    }
+
+    pub fn get(&self, day: u8) -> Option<PathBuf> {
+        Some(self.path(day)).filter(|path| path.exists())
+    }
+}
+
+/// The input of a day: `<root>/<day>/input.txt` if it is there, the cached download otherwise.
+/// When neither exists this is still the `input.txt`, so errors name the file people expect.
+pub fn input_path(root: &Path, day: u8) -> PathBuf {
+    let local = root.join(format!("{:02}", day)).join("input.txt");
+    if local.exists() {
+        return local;
+    }
+    Config::load().ok().and_then(|config| Cache::new(config.cache_dir).get(day)).unwrap_or(local)
+}
+
+/// How requests are sent, so tests don't need the network
+pub trait Http {
+    /// The body of a successful response, a description of the problem otherwise
+    fn get(&self, url: &str, session: &str) -> Result<String, String>;
+}
+
+pub struct Ureq;
+
+impl Http for Ureq {
+    fn get(&self, url: &str, session: &str) -> Result<String, String> {
+        let response = ureq::get(url)
+            .set("Cookie", &format!("session={}", session))
+            .set("User-Agent", concat!("aoc-2023 runner ", env!("CARGO_PKG_VERSION"), " (ureq)"))
+            .call();
+        match response {
+            Ok(response) => response.into_string().map_err(|e| e.to_string()),
+            Err(ureq::Error::Status(code @ (400 | 401), _)) => {
+                Err(format!("the server answered {}, is the session token still valid?", code))
+            }
+            Err(ureq::Error::Status(404, _)) => Err("the server answered 404, is the puzzle unlocked yet?".to_string()),
+            Err(ureq::Error::Status(code, response)) => {
+                Err(format!("the server answered {} {}", code, response.status_text()))
+            }
+            Err(e) => Err(e.to_string()),
+        }
+    }
+}
+
+pub struct Fetcher<H: Http> {
+    config: Config,
+    http: H,
+}
+
+impl<H: Http> Fetcher<H> {
+    pub fn new(config: Config, http: H) -> Self {
+        Fetcher { config, http }
+    }
+
+    /// The cached input of `day` and whether it had to be downloaded for that
+    pub fn fetch(&self, day: u8) -> Result<(PathBuf, bool), String> {
+        let cache = Cache::new(&self.config.cache_dir);
+        if let Some(path) = cache.get(day) {
+            return Ok((path, false));
+        }
+
+        let Some(session) = self.config.session.as_deref() else {
+            let path = Config::path().map_or("the config file".to_string(), |p| p.display().to_string());
+            return Err(format!("no session token configured, add `session = \"...\"` to {}", path));
+        };
+
+        create_dir_all(&self.config.cache_dir)
+            .map_err(|e| format!("could not create {}: {}", self.config.cache_dir.display(), e))?;
+        self.wait_for_turn()?;
+
+        let url = format!("{}/2023/day/{}/input", self.config.url, day);
+        let input = self.http.get(&url, session).map_err(|e| format!("day {:02}: {}", day, e))?;
+        if input.trim().is_empty() {
+            return Err(format!("day {:02}: the server sent an empty input", day));
+        }
+
+        // Written next to the final file and renamed, so an interrupted download is
+        // never mistaken for a cached input
+        let path = cache.path(day);
+        let partial = path.with_extension("part");
+        write(&partial, input).map_err(|e| format!("could not write {}: {}", partial.display(), e))?;
+        rename(&partial, &path).map_err(|e| format!("could not write {}: {}", path.display(), e))?;
+        Ok((path, true))
+    }
+
+    // The time of the last request is kept in the cache (in milliseconds since the epoch),
+    // so the limit also holds across separate runs of `aoc fetch`
+    fn wait_for_turn(&self) -> Result<(), String> {
+        let stamp = self.config.cache_dir.join(".last-request");
+        let last = read_to_string(&stamp).ok().and_then(|s| s.trim().parse().ok()).map(Duration::from_millis);
+        if let Some(last) = last {
+            let since_last = now().saturating_sub(last);
+            if since_last < self.config.min_interval {
+                sleep(self.config.min_interval - since_last);
+            }
+        }
+        write(&stamp, now().as_millis().to_string())
+            .map_err(|e| format!("could not write {}: {}", stamp.display(), e))
+    }
+}
+
+fn now() -> Duration {
+    SystemTime::now().duration_since(UNIX_EPOCH).unwrap_or_default()
+}
@@ -0,0 +1,10 @@
+pub mod alloc;
+pub mod answers;
+pub mod bench;
+pub mod days;
+pub mod demos;
+pub mod fetch;
+pub mod output;
+pub mod parallel;
+pub mod render;
+pub mod vis;
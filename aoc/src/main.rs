@@ -0,0 +1,538 @@
+use std::{fs::{read_to_string, write, File}, io::{self, Read}, path::{Path, PathBuf}, process::ExitCode, sync::Arc, thread, time::{Duration, Instant}};
+
+use aoc::{
+    alloc,
+    answers::{Feedback, Store},
+    bench,
+    days::{self, Parsed},
+    fetch::{self, Config, Fetcher, Ureq},
+    output::{self, Format, PartResult},
+    parallel::{self, Job, Outcome},
+    render, vis,
+};
+use aoc_2023_01::{stream, CalibrationDecoder, Policy};
+use aoc_2023_02::query;
+use aoc_common::{Params, Recording};
+use clap::{Parser, Subcommand, ValueEnum};
+use memmap2::Mmap;
+
+#[derive(Parser)]
+#[command(name = "aoc", about = "Runs the Advent of Code 2023 solutions")]
+struct Cli {
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Run the solution of a single day or of all days in order
+    Run {
+        /// Day to run
+        #[arg(short, long, value_parser = clap::value_parser!(u8).range(1..=25), required_unless_present = "all")]
+        day: Option<u8>,
+        /// Only run this part
+        #[arg(short, long, value_parser = clap::value_parser!(u8).range(1..=2))]
+        part: Option<u8>,
+        /// Input file, `-` reads stdin (defaults to `<day>/input.txt`, or the input downloaded by `aoc fetch`)
+        #[arg(short, long, conflicts_with = "all")]
+        input: Option<PathBuf>,
+        /// Override a parameter of the day, e.g. `steps=6` to run day 21 on its demo
+        #[arg(long = "param", value_name = "NAME=VALUE", conflicts_with = "all")]
+        params: Vec<String>,
+        /// Run every day, several at a time, and sum up which parts passed
+        #[arg(long, conflicts_with = "day")]
+        all: bool,
+        /// Days to run at the same time with `--all` (defaults to the number of CPUs).
+        /// Allocation counts are only exact with 1.
+        #[arg(long, requires = "all")]
+        jobs: Option<usize>,
+        /// Seconds a day may take with `--all`, after that its parts count as timed out
+        #[arg(long, requires = "all", default_value_t = 60)]
+        timeout: u64,
+        /// How to print the answers
+        #[arg(long, value_enum, default_value_t = Format::Human)]
+        format: Format,
+        /// Draw the answer of the part given with `--part` (part 1 without it) into an .svg
+        /// or .png file. Days 10, 17, 18, 21 and 23 have pictures.
+        #[arg(long, value_name = "FILE", conflicts_with = "all")]
+        render: Option<PathBuf>,
+        /// Show how the answer of the part given with `--part` (part 1 without it) comes
+        /// about. Day 01 explains its answers.
+        #[arg(long, conflicts_with = "all")]
+        explain: bool,
+    },
+    /// Record what the puzzle page said about an answer in `answers.toml`
+    ///
+    /// `aoc run` then tells whether the answers it computes on the real input are
+    /// confirmed, known to be wrong or outside the known bounds.
+    Answer {
+        /// Day of the answer
+        #[arg(short, long, value_parser = clap::value_parser!(u8).range(1..=25))]
+        day: u8,
+        /// Part of the answer
+        #[arg(short, long, value_parser = clap::value_parser!(u8).range(1..=2))]
+        part: u8,
+        /// What the puzzle page said
+        #[arg(value_enum)]
+        feedback: Feedback,
+        /// The submitted answer
+        answer: String,
+    },
+    /// Download the input of a day into the cache, unless it is already there
+    ///
+    /// Needs the session token in the config file, see `aoc fetch --help` for its location.
+    #[command(after_help = CONFIG_HELP)]
+    Fetch {
+        /// Day to download
+        #[arg(short, long, value_parser = clap::value_parser!(u8).range(1..=25))]
+        day: u8,
+    },
+    /// Show how the solution of a day works, frame by frame
+    ///
+    /// Plays in the terminal: Enter pauses, while paused Enter steps forward, `b` goes
+    /// back, `c` continues and `q` quits. Days 14, 16, 20, 21 and 22 have visualizations.
+    Vis {
+        /// Day to show
+        #[arg(short, long, value_parser = clap::value_parser!(u8).range(1..=25))]
+        day: u8,
+        /// Input file, `-` reads stdin (defaults to `<day>/input.txt`, or the input downloaded by `aoc fetch`)
+        #[arg(short, long)]
+        input: Option<PathBuf>,
+        /// Override a parameter of the day, e.g. `steps=6` to run day 21 on its demo
+        #[arg(long = "param", value_name = "NAME=VALUE")]
+        params: Vec<String>,
+        /// Milliseconds between two frames
+        #[arg(long, default_value_t = 100)]
+        delay: u64,
+        /// Start paused and step through the frames with Enter
+        #[arg(long)]
+        step: bool,
+        /// Write the frames to an asciicast file instead of playing them
+        #[arg(long)]
+        cast: Option<PathBuf>,
+        /// Don't highlight cells with colors
+        #[arg(long)]
+        no_color: bool,
+    },
+    /// Time parsing and both parts of every day (or a single one)
+    ///
+    /// Days are timed on `<day>/input.txt`, or on their demo input when that is missing.
+    Bench {
+        /// Only benchmark this day
+        #[arg(short, long, value_parser = clap::value_parser!(u8).range(1..=25))]
+        day: Option<u8>,
+        /// How long to repeat each stage for, in milliseconds
+        #[arg(long, default_value_t = 1000)]
+        time: u64,
+        /// Save the results as JSON
+        #[arg(long)]
+        save: Option<PathBuf>,
+        /// Compare against results saved earlier with `--save`
+        #[arg(long)]
+        baseline: Option<PathBuf>,
+    },
+    /// Sum up the calibration values of a document of any size like day 01 does
+    ///
+    /// The document is read in chunks that are decoded in parallel, so it doesn't have
+    /// to fit into memory.
+    Calibrate {
+        /// Document to read, `-` reads stdin
+        input: PathBuf,
+        /// Decode like part 2, with spelled out digits
+        #[arg(short, long, value_parser = clap::value_parser!(u8).range(1..=2), default_value_t = 1)]
+        part: u8,
+        /// What to do with lines without a digit
+        #[arg(long, value_enum, default_value_t = MissingDigits::Fail)]
+        missing: MissingDigits,
+        /// Map the file into memory instead of reading it
+        #[arg(long)]
+        mmap: bool,
+    },
+    /// Select day 02 games with a condition and add up a value of the selected games
+    ///
+    /// `aoc query 'max(red) <= 12 && max(green) <= 13 && max(blue) <= 14'` gives the
+    /// answer of part 1, `aoc query true --value 'product()'` the one of part 2. Values
+    /// are numbers, `id`, `rounds`, `max(color)` and `min(color)` over the rounds, and
+    /// `sum(colors...)` and `product(colors...)` of the fewest cubes the bag can hold, of
+    /// all colors without any. Conditions compare values with `<`, `<=`, `==`, `!=`, `>=`
+    /// and `>` and combine with `&&`, `||`, `!` and parentheses.
+    Query {
+        /// Which games to select
+        condition: String,
+        /// What to add up over the selected games
+        #[arg(long, default_value = "id")]
+        value: String,
+        /// Input file, `-` reads stdin [default: the input of day 02]
+        #[arg(short, long)]
+        input: Option<PathBuf>,
+        /// Print each selected game with its value
+        #[arg(long)]
+        list: bool,
+    },
+    /// Write a random input for a day that both parts can solve
+    ///
+    /// The same seed and size always give the same input. Handy to see how a day scales
+    /// with the size of its input.
+    Gen {
+        /// Day to generate an input for
+        #[arg(short, long, value_parser = clap::value_parser!(u8).range(1..=25))]
+        day: u8,
+        /// How big the input gets, what it counts depends on the day: lines, the side of a grid, ...
+        #[arg(long, default_value_t = 100)]
+        size: usize,
+        /// Seed of the random numbers
+        #[arg(long, default_value_t = 0)]
+        seed: u64,
+        /// Write the input to this file instead of stdout
+        #[arg(short, long)]
+        output: Option<PathBuf>,
+    },
+}
+
+#[derive(Clone, Copy, ValueEnum)]
+enum MissingDigits {
+    Fail,
+    Skip,
+    Zero,
+}
+
+const CONFIG_HELP: &str = "\
+The config file is `aoc-2023/config.toml` in the user's config directory, or the file \
+AOC_CONFIG points to:
+
+  session = \"53616c7465645f5f...\"      # the session cookie of adventofcode.com
+  url = \"https://adventofcode.com\"     # optional
+  cache = \"/home/me/.cache/aoc-2023\"   # optional, where inputs are stored
+  min_interval = 5                     # optional, seconds between two downloads";
+
+fn default_input(day: u8) -> PathBuf {
+    fetch::input_path(Path::new("."), day)
+}
+
+fn record_answer(day: u8, part: u8, feedback: Feedback, answer: &str) -> Result<(), String> {
+    let mut store = Store::load(Path::new(ANSWERS))?;
+    store.record(day, part, answer.trim(), feedback)?;
+    store.save()
+}
+
+fn run_fetch(day: u8) -> Result<(), String> {
+    let fetcher = Fetcher::new(Config::load()?, Ureq);
+    let (path, downloaded) = fetcher.fetch(day)?;
+    if downloaded {
+        println!("day {:02}: downloaded to {}", day, path.display());
+    } else {
+        println!("day {:02}: already cached at {}", day, path.display());
+    }
+    Ok(())
+}
+
+const ANSWERS: &str = "answers.toml";
+
+fn read_input(day: u8, input_path: &Path) -> Result<String, String> {
+    if input_path == Path::new("-") {
+        let mut input = String::new();
+        io::stdin().read_to_string(&mut input).map_err(|e| format!("day {:02}: could not read stdin: {}", day, e))?;
+        return Ok(input);
+    }
+    read_to_string(input_path).map_err(|e| format!("day {:02}: could not read {}: {}", day, input_path.display(), e))
+}
+
+fn parse_params(day: u8, assignments: &[String]) -> Result<Params, String> {
+    let mut params = Params::new();
+    for assignment in assignments {
+        params.set_from_str(assignment).map_err(|e| format!("--param: {}", e))?;
+    }
+    days::check_params(day, &params).map_err(|e| {
+        let mut message = format!("day {:02}: {}", day, e);
+        for param in days::params(day) {
+            message += &format!("\n  {} (default {}): {}", param.name, param.default, param.help);
+        }
+        message
+    })?;
+    Ok(params)
+}
+
+fn load(day: u8, input_path: &Path) -> Result<Box<dyn Parsed>, String> {
+    let input = read_input(day, input_path)?;
+    days::get(day)(&input).map_err(|e| e.on_day(day).to_string())
+}
+
+// `store` is only given for the real inputs
+fn solve(parsed: &dyn Parsed, day: u8, part: u8, params: &Params, store: Option<&Store>) -> Result<PartResult, String> {
+    let ((answer, elapsed), memory) = alloc::measure(|| {
+        let start = Instant::now();
+        let answer = parsed.part(part, params);
+        (answer, start.elapsed())
+    });
+    let answer = answer.map_err(|e| format!("day {:02} part {}: {}", day, part, e))?;
+    let verdict = store.map(|store| store.check(day, part, &answer));
+    Ok(PartResult { part, answer, elapsed, verdict, memory })
+}
+
+fn parts(part: Option<u8>) -> Vec<u8> {
+    match part {
+        Some(part) => vec![part],
+        None => vec![1, 2],
+    }
+}
+
+#[allow(clippy::too_many_arguments)]
+fn run_day(day: u8, part: Option<u8>, input_path: &Path, params: &Params, format: Format, store: Option<&Store>, render: Option<&Path>, explain: bool) -> Result<(), String> {
+    let parsed = load(day, input_path)?;
+    let results = parts(part).into_iter().map(|part| solve(parsed.as_ref(), day, part, params, store)).collect::<Result<Vec<_>, _>>()?;
+    print!("{}", output::day(format, day, &results));
+
+    if let Some(path) = render {
+        let part = part.unwrap_or(1);
+        let scene = parsed.render(params, part).ok_or_else(|| format!("day {:02} has no picture of part {}", day, part))?;
+        render::save(&scene, path)?;
+    }
+    if explain {
+        let part = part.unwrap_or(1);
+        let explanation = parsed.explain(params, part).ok_or_else(|| format!("day {:02} can't explain part {}", day, part))?;
+        print!("\n{}", explanation);
+    }
+    Ok(())
+}
+
+// Every day on its real input, `jobs` at a time. Returns whether all parts passed.
+fn run_all(part: Option<u8>, jobs: usize, timeout: Duration, format: Format, store: Store) -> bool {
+    let parts = parts(part);
+    let job_parts = parts.clone();
+    let job: Arc<Job> = Arc::new(move |day, report| {
+        let parsed = match load(day, &default_input(day)) {
+            Ok(parsed) => parsed,
+            Err(e) => {
+                for &part in &job_parts {
+                    report(part, Outcome::Failed(e.clone()));
+                }
+                return;
+            }
+        };
+        for &part in &job_parts {
+            let result = parallel::catch(|| solve(parsed.as_ref(), day, part, &Params::new(), Some(&store)));
+            let result = result.map_err(|e| format!("day {:02} part {}: {}", day, part, e)).and_then(|result| result);
+            report(part, result.map_or_else(Outcome::Failed, Outcome::Done));
+        }
+    });
+
+    let days: Vec<u8> = (1..=25).collect();
+    let mut reports = Vec::new();
+    parallel::run(&days, &parts, jobs, timeout, job, |report| {
+        let mut results = Vec::new();
+        for (part, outcome) in report.parts.iter() {
+            match outcome {
+                Outcome::Done(result) => results.push(result.clone()),
+                Outcome::Failed(message) => eprintln!("error: {}", message),
+                Outcome::TimedOut => eprintln!("error: day {:02} part {}: timed out after {:?}", report.day, part, timeout),
+            }
+        }
+        if !results.is_empty() {
+            print!("{}", output::day(format, report.day, &results));
+        }
+        reports.push(report);
+    });
+
+    eprint!("\n{}", parallel::summary(&reports, &parts));
+    reports.iter().flat_map(|r| r.parts.iter()).all(|(_, outcome)| outcome.status() == "pass")
+}
+
+struct VisOptions {
+    delay: Duration,
+    step: bool,
+    cast: Option<PathBuf>,
+    color: bool,
+}
+
+fn run_vis(day: u8, input_path: &Path, params: &Params, options: VisOptions) -> Result<(), String> {
+    let input = read_input(day, input_path)?;
+    let parsed = days::get(day)(&input).map_err(|e| e.on_day(day).to_string())?;
+    let mut recording = Recording::new();
+    parsed.visualize(params, &mut recording);
+    if recording.frames().is_empty() {
+        return Err(format!("day {:02} has no visualization", day));
+    }
+
+    match options.cast {
+        Some(path) => {
+            let cast = vis::asciicast(recording.frames(), options.delay, options.color);
+            write(&path, cast).map_err(|e| format!("could not write {}: {}", path.display(), e))
+        }
+        None => {
+            vis::play(recording.frames(), options.delay, options.color, options.step);
+            Ok(())
+        }
+    }
+}
+
+fn run_bench(day: Option<u8>, time: u64, save: Option<PathBuf>, baseline: Option<PathBuf>) -> Result<(), String> {
+    let baseline: Option<bench::Timings> = match baseline {
+        Some(path) => {
+            let json = read_to_string(&path).map_err(|e| format!("could not read {}: {}", path.display(), e))?;
+            Some(serde_json::from_str(&json).map_err(|e| format!("{} is not a saved benchmark: {}", path.display(), e))?)
+        }
+        None => None,
+    };
+
+    let days = match day {
+        Some(day) => day..=day,
+        None => 1..=25,
+    };
+    let mut timings = bench::Timings::new();
+    for day in days {
+        let input = bench::input(Path::new("."), day);
+        if input.is_demo {
+            eprintln!("day {:02}: no input.txt, timing {} instead", day, input.path.display());
+        }
+        timings.insert(format!("{:02}", day), bench::run(day, &input, Duration::from_millis(time))?);
+    }
+
+    print!("{}", bench::table(&timings, baseline.as_ref()));
+
+    if let Some(path) = save {
+        let json = serde_json::to_string_pretty(&timings).unwrap();
+        write(&path, json).map_err(|e| format!("could not write {}: {}", path.display(), e))?;
+    }
+    Ok(())
+}
+
+fn run_calibrate(input: &Path, part: u8, missing: MissingDigits, mmap: bool) -> Result<(), String> {
+    let decoder = CalibrationDecoder::new(if part == 1 { aoc_2023_01::DIGITS } else { aoc_2023_01::WORDS });
+    let policy = match missing {
+        MissingDigits::Fail => Policy::Strict,
+        MissingDigits::Skip => Policy::Skip,
+        MissingDigits::Zero => Policy::Zero,
+    };
+    let read_error = |e: io::Error| format!("could not read {}: {}", input.display(), e);
+
+    let start = Instant::now();
+    let totals = if input == Path::new("-") {
+        stream::calibrate_reader(&decoder, io::stdin(), policy).map_err(|e| e.to_string())?
+    } else {
+        let file = File::open(input).map_err(read_error)?;
+        if mmap {
+            // SAFETY: the map is only read, changing the file meanwhile at worst gives a wrong sum
+            let map = unsafe { Mmap::map(&file) }.map_err(read_error)?;
+            stream::calibrate_bytes(&decoder, &map, policy).map_err(|e| e.to_string())?
+        } else {
+            stream::calibrate_reader(&decoder, file, policy).map_err(|e| e.to_string())?
+        }
+    };
+    let elapsed = start.elapsed();
+
+    println!("{}", totals.sum);
+    eprintln!(
+        "{} lines, {} without a digit, {} in {:.2?}: {:.1} MB/s",
+        totals.lines,
+        totals.missing,
+        alloc::format_bytes(totals.bytes),
+        elapsed,
+        totals.bytes as f64 / elapsed.as_secs_f64() / 1e6
+    );
+    Ok(())
+}
+
+fn run_query(condition: &str, value: &str, input: &Path, list: bool) -> Result<(), String> {
+    let condition = query::parse_condition(condition).map_err(|e| format!("condition: {}", e))?;
+    let value = query::parse_value(value).map_err(|e| format!("--value: {}", e))?;
+    let games = aoc_2023_02::parse(&read_input(2, input)?).map_err(|e| e.on_day(2).to_string())?;
+
+    let mut selected = 0;
+    let mut total = 0usize;
+    for game in query::select(&games, &condition) {
+        let value = value.eval(&games.colors, game);
+        if list {
+            println!("Game {}: {}", game.id, value);
+        }
+        selected += 1;
+        total = total.saturating_add(value);
+    }
+    println!("{}", total);
+    eprintln!("{} of {} games selected", selected, games.games.len());
+    Ok(())
+}
+
+fn main() -> ExitCode {
+    let cli = Cli::parse();
+
+    match cli.command {
+        Command::Run { day, part, input, params, all, jobs, timeout, format, render, explain } => {
+            let store = match Store::load(Path::new(ANSWERS)) {
+                Ok(store) => store,
+                Err(e) => {
+                    eprintln!("error: {}", e);
+                    return ExitCode::FAILURE;
+                }
+            };
+            if all {
+                let jobs = jobs.unwrap_or_else(|| thread::available_parallelism().map_or(1, |n| n.get()));
+                if !run_all(part, jobs, Duration::from_secs(timeout), format, store) {
+                    return ExitCode::FAILURE;
+                }
+            } else {
+                let day = day.unwrap();
+                // Answers only count for the real input with the real parameters
+                let store = if input.is_none() && params.is_empty() { Some(&store) } else { None };
+                let input = input.unwrap_or_else(|| default_input(day));
+                let result = parse_params(day, &params).and_then(|params| run_day(day, part, &input, &params, format, store, render.as_deref(), explain));
+                if let Err(e) = result {
+                    eprintln!("error: {}", e);
+                    return ExitCode::FAILURE;
+                }
+            }
+        }
+        Command::Vis { day, input, params, delay, step, cast, no_color } => {
+            let input = input.unwrap_or_else(|| default_input(day));
+            let options = VisOptions { delay: Duration::from_millis(delay), step, cast, color: !no_color };
+            if let Err(e) = parse_params(day, &params).and_then(|params| run_vis(day, &input, &params, options)) {
+                eprintln!("error: {}", e);
+                return ExitCode::FAILURE;
+            }
+        }
+        Command::Answer { day, part, feedback, answer } => {
+            if let Err(e) = record_answer(day, part, feedback, &answer) {
+                eprintln!("error: {}", e);
+                return ExitCode::FAILURE;
+            }
+        }
+        Command::Fetch { day } => {
+            if let Err(e) = run_fetch(day) {
+                eprintln!("error: {}", e);
+                return ExitCode::FAILURE;
+            }
+        }
+        Command::Bench { day, time, save, baseline } => {
+            if let Err(e) = run_bench(day, time, save, baseline) {
+                eprintln!("error: {}", e);
+                return ExitCode::FAILURE;
+            }
+        }
+        Command::Calibrate { input, part, missing, mmap } => {
+            if let Err(e) = run_calibrate(&input, part, missing, mmap) {
+                eprintln!("error: {}", e);
+                return ExitCode::FAILURE;
+            }
+        }
+        Command::Query { condition, value, input, list } => {
+            let input = input.unwrap_or_else(|| default_input(2));
+            if let Err(e) = run_query(&condition, &value, &input, list) {
+                eprintln!("error: {}", e);
+                return ExitCode::FAILURE;
+            }
+        }
+        Command::Gen { day, size, seed, output } => {
+            let input = days::generate(day, seed, size);
+            match output {
+                Some(path) => {
+                    if let Err(e) = write(&path, input) {
+                        eprintln!("error: could not write {}: {}", path.display(), e);
+                        return ExitCode::FAILURE;
+                    }
+                }
+                None => print!("{}", input),
+            }
+        }
+    }
+
+    ExitCode::SUCCESS
+}
@@ -0,0 +1,115 @@
+use std::time::Duration;
+
+use aoc_common::Answer;
+
+use crate::{alloc::{format_bytes, Memory}, answers::Verdict, bench::format_nanos};
+use clap::ValueEnum;
+use serde_json::{json, Value};
+
+/// How `aoc run` prints its answers. Only answers go to stdout, everything else
+/// (errors, notes, debug output of the days) goes to stderr.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum Format {
+    /// A heading per day and a line per part
+    Human,
+    /// One JSON object per part and line: `{"day", "part", "answer", "elapsed_ms"}`, plus
+    /// `"status"` when the answer could be checked against `answers.toml`, and
+    /// `"allocations"`, `"allocated_bytes"` and `"peak_bytes"` with the `alloc-stats` feature
+    Json,
+}
+
+#[derive(Clone)]
+pub struct PartResult {
+    pub part: u8,
+    pub answer: Answer,
+    pub elapsed: Duration,
+    /// Only for the real input
+    pub verdict: Option<Verdict>,
+    /// Only with the `alloc-stats` feature
+    pub memory: Option<Memory>,
+}
+
+/// Small answers are JSON numbers, big ones strings so they survive being parsed as doubles
+fn answer_json(answer: &Answer) -> Value {
+    match answer {
+        Answer::Int(n) if n.unsigned_abs() < 1 << 53 => json!(n),
+        other => json!(other.to_string()),
+    }
+}
+
+/// The lines printed for one day
+pub fn day(format: Format, day: u8, parts: &[PartResult]) -> String {
+    let mut out = String::new();
+    match format {
+        Format::Human => {
+            out += &format!("Day {:02}\n", day);
+            for result in parts {
+                out += &format!("  part {}: {}", result.part, result.answer);
+                match &result.verdict {
+                    None | Some(Verdict::Unknown) => {}
+                    Some(verdict) => out += &format!(" ({})", verdict),
+                }
+                if let Some(memory) = &result.memory {
+                    out += &format!(
+                        "  [{}, {} allocations, {} allocated, {} peak]",
+                        format_nanos(result.elapsed.as_nanos() as u64),
+                        memory.allocations,
+                        format_bytes(memory.allocated),
+                        format_bytes(memory.peak)
+                    );
+                }
+                out.push('\n');
+            }
+        }
+        Format::Json => {
+            for result in parts {
+                let mut line = json!({
+                    "day": day,
+                    "part": result.part,
+                    "answer": answer_json(&result.answer),
+                    "elapsed_ms": result.elapsed.as_secs_f64() * 1000.0,
+                });
+                if let Some(verdict) = &result.verdict {
+                    line["status"] = json!(verdict.name());
+                }
+                if let Some(memory) = &result.memory {
+                    line["allocations"] = json!(memory.allocations);
+                    line["allocated_bytes"] = json!(memory.allocated);
+                    line["peak_bytes"] = json!(memory.peak);
+                }
+                out += &format!("{}\n", line);
+            }
+        }
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn json_lines() {
+        let result = |part, answer: Answer, elapsed, verdict| PartResult { part, answer, elapsed, verdict, memory: None };
+        let parts = [
+            result(1, Answer::from(142), Duration::from_micros(1500), None),
+            result(2, Answer::from(u64::MAX), Duration::ZERO, Some(Verdict::TooHigh(100))),
+            result(2, Answer::from("Merry Christmas!"), Duration::ZERO, None),
+        ];
+        let out = day(Format::Json, 1, &parts);
+        let lines: Vec<Value> = out.lines().map(|l| serde_json::from_str(l).unwrap()).collect();
+        assert_eq!(lines[0], json!({"day": 1, "part": 1, "answer": 142, "elapsed_ms": 1.5}));
+        assert_eq!(lines[1]["answer"], json!("18446744073709551615"));
+        assert_eq!(lines[1]["status"], json!("too_high"));
+        assert_eq!(lines[2]["answer"], json!("Merry Christmas!"));
+    }
+
+    #[test]
+    fn memory() {
+        let memory = Memory { allocations: 3, allocated: 2048, peak: 1000 };
+        let parts = [PartResult { part: 1, answer: Answer::from(142), elapsed: Duration::from_micros(1500), verdict: None, memory: Some(memory) }];
+        assert_eq!(day(Format::Human, 1, &parts), "Day 01\n  part 1: 142  [1.5 ms, 3 allocations, 2.0 KiB allocated, 1000 B peak]\n");
+        let line: Value = serde_json::from_str(&day(Format::Json, 1, &parts)).unwrap();
+        assert_eq!((&line["allocations"], &line["allocated_bytes"], &line["peak_bytes"]), (&json!(3), &json!(2048), &json!(1000)));
+    }
+}
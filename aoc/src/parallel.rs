@@ -0,0 +1,214 @@
+use std::{
+    any::Any,
+    collections::{BTreeMap, VecDeque},
+    panic::{self, AssertUnwindSafe},
+    sync::{
+        mpsc::{self, RecvTimeoutError},
+        Arc,
+    },
+    thread,
+    time::{Duration, Instant},
+};
+
+use crate::{answers::Verdict, output::PartResult};
+
+/// How a part went in `aoc run --all`
+pub enum Outcome {
+    Done(PartResult),
+    /// The input could not be read or parsed, or the part panicked. The message tells
+    /// the day.
+    Failed(String),
+    TimedOut,
+}
+
+impl Outcome {
+    /// Pass, fail or timeout, answers known to be wrong fail
+    pub fn status(&self) -> &'static str {
+        match self {
+            Outcome::Done(result) => match result.verdict {
+                Some(Verdict::Mismatch(_) | Verdict::KnownWrong | Verdict::TooHigh(_) | Verdict::TooLow(_)) => "fail",
+                _ => "pass",
+            },
+            Outcome::Failed(_) => "fail",
+            Outcome::TimedOut => "timeout",
+        }
+    }
+}
+
+pub struct DayReport {
+    pub day: u8,
+    /// In the order of the parts that were asked for
+    pub parts: Vec<(u8, Outcome)>,
+}
+
+/// Solves a day on a thread of the pool, reporting each part as soon as it is done
+pub type Job = dyn Fn(u8, &mut dyn FnMut(u8, Outcome)) + Send + Sync;
+
+/// The message of a caught panic
+pub fn panic_message(payload: Box<dyn Any + Send>) -> String {
+    match payload.downcast::<String>() {
+        Ok(message) => format!("panicked: {}", message),
+        Err(payload) => match payload.downcast::<&str>() {
+            Ok(message) => format!("panicked: {}", message),
+            Err(_) => "panicked".to_string(),
+        },
+    }
+}
+
+/// Runs `f`, turning a panic into an error
+pub fn catch<T>(f: impl FnOnce() -> T) -> Result<T, String> {
+    panic::catch_unwind(AssertUnwindSafe(f)).map_err(panic_message)
+}
+
+enum Event {
+    Part(u8, u8, Outcome),
+    Panicked(u8, String),
+    Finished(u8),
+}
+
+/// Runs `job` for every day, at most `workers` at a time, and hands the reports to
+/// `on_report` in the order of `days`. A day gets `timeout` to run all its `parts`,
+/// after that its thread is left behind and the parts it didn't finish time out. A
+/// panic of the job fails the parts it didn't finish.
+pub fn run(days: &[u8], parts: &[u8], workers: usize, timeout: Duration, job: Arc<Job>, mut on_report: impl FnMut(DayReport)) {
+    let (sender, events) = mpsc::channel();
+    let mut waiting: VecDeque<u8> = days.iter().copied().collect();
+    let mut running: BTreeMap<u8, Instant> = BTreeMap::new();
+    let mut outcomes: BTreeMap<u8, BTreeMap<u8, Outcome>> = BTreeMap::new();
+    let mut finished: BTreeMap<u8, DayReport> = BTreeMap::new();
+    let mut next = 0;
+
+    loop {
+        while running.len() < workers.max(1) {
+            let Some(day) = waiting.pop_front() else { break };
+            running.insert(day, Instant::now() + timeout);
+            outcomes.insert(day, BTreeMap::new());
+            let (job, sender) = (job.clone(), sender.clone());
+            thread::spawn(move || {
+                let part_sender = sender.clone();
+                let result = catch(|| job(day, &mut |part, outcome| drop(part_sender.send(Event::Part(day, part, outcome)))));
+                if let Err(message) = result {
+                    let _ = sender.send(Event::Panicked(day, format!("day {:02}: {}", day, message)));
+                }
+                let _ = sender.send(Event::Finished(day));
+            });
+        }
+        if running.is_empty() {
+            break;
+        }
+
+        let deadline = *running.values().min().unwrap();
+        let mut done = Vec::new();
+        match events.recv_timeout(deadline.saturating_duration_since(Instant::now())) {
+            // Late events of days that timed out are dropped
+            Ok(Event::Part(day, part, outcome)) if running.contains_key(&day) => {
+                outcomes.get_mut(&day).unwrap().insert(part, outcome);
+            }
+            // Every part the job didn't get to fails with it
+            Ok(Event::Panicked(day, message)) if running.contains_key(&day) => {
+                let day_outcomes = outcomes.get_mut(&day).unwrap();
+                for &part in parts {
+                    day_outcomes.entry(part).or_insert_with(|| Outcome::Failed(message.clone()));
+                }
+            }
+            Ok(Event::Finished(day)) if running.contains_key(&day) => done.push((day, false)),
+            Ok(_) => {}
+            Err(RecvTimeoutError::Timeout) => {
+                let now = Instant::now();
+                done.extend(running.iter().filter(|&(_, &deadline)| deadline <= now).map(|(&day, _)| (day, true)));
+            }
+            Err(RecvTimeoutError::Disconnected) => unreachable!("the sender is still alive"),
+        }
+
+        for (day, timed_out) in done {
+            running.remove(&day);
+            let mut day_outcomes = outcomes.remove(&day).unwrap();
+            let parts = parts
+                .iter()
+                .filter_map(|part| match (day_outcomes.remove(part), timed_out) {
+                    (Some(outcome), _) => Some((*part, outcome)),
+                    (None, true) => Some((*part, Outcome::TimedOut)),
+                    // The job didn't report a part it wasn't asked for
+                    (None, false) => None,
+                })
+                .collect();
+            finished.insert(day, DayReport { day, parts });
+        }
+        while let Some(report) = days.get(next).and_then(|day| finished.remove(day)) {
+            on_report(report);
+            next += 1;
+        }
+    }
+}
+
+/// A row per day with the status of each part, and how many parts passed, failed and
+/// timed out
+pub fn summary(reports: &[DayReport], parts: &[u8]) -> String {
+    let mut out = format!("{:<5}", "day");
+    for part in parts {
+        out += &format!("{:<9}", format!("part {}", part));
+    }
+    out = out.trim_end().to_string() + "\n";
+
+    let mut counts: BTreeMap<&str, usize> = BTreeMap::new();
+    for report in reports {
+        let mut row = format!("{:<5}", format!("{:02}", report.day));
+        for part in parts {
+            let status = report.parts.iter().find(|(p, _)| p == part).map_or("-", |(_, outcome)| outcome.status());
+            if status != "-" {
+                *counts.entry(status).or_default() += 1;
+            }
+            row += &format!("{:<9}", status);
+        }
+        out += row.trim_end();
+        out.push('\n');
+    }
+
+    let count = |status| counts.get(status).copied().unwrap_or(0);
+    out += &format!("{} passed, {} failed, {} timed out\n", count("pass"), count("fail"), count("timeout"));
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_common::Answer;
+
+    fn done(part: u8, verdict: Option<Verdict>) -> Outcome {
+        Outcome::Done(PartResult { part, answer: Answer::from(1), elapsed: Duration::ZERO, verdict, memory: None })
+    }
+
+    #[test]
+    fn isolates_days() {
+        let job: Arc<Job> = Arc::new(|day, report| match day {
+            1 => {
+                report(1, done(1, None));
+                report(2, done(2, Some(Verdict::TooLow(5))));
+            }
+            2 => {
+                report(1, done(1, None));
+                panic!("no input");
+            }
+            3 => {
+                report(1, done(1, None));
+                thread::sleep(Duration::from_secs(60));
+            }
+            _ => {
+                let outcome = catch(|| -> PartResult { panic!("at part 1") }).map_or_else(Outcome::Failed, Outcome::Done);
+                report(1, outcome);
+                report(2, done(2, None));
+            }
+        });
+
+        let mut reports = Vec::new();
+        run(&[1, 2, 3, 4], &[1, 2], 2, Duration::from_millis(200), job, |report| reports.push(report));
+
+        assert_eq!(reports.iter().map(|r| r.day).collect::<Vec<_>>(), [1, 2, 3, 4]);
+        let Outcome::Failed(message) = &reports[1].parts[1].1 else { panic!("day 2 part 2 should fail") };
+        assert_eq!(message, "day 02: panicked: no input");
+        assert_eq!(
+            summary(&reports, &[1, 2]),
+            "day  part 1   part 2\n01   pass     fail\n02   pass     fail\n03   pass     timeout\n04   fail     pass\n4 passed, 3 failed, 1 timed out\n"
+        );
+    }
+}
@@ -0,0 +1,213 @@
+use std::{fs::write, path::Path};
+
+use aoc_common::{Rgb, Scene, Shape};
+
+/// Pixels per cell, unless that makes the picture too big
+const CELL_PIXELS: f64 = 8.0;
+/// Pixels on the longest side at most
+const MAX_PIXELS: f64 = 2000.0;
+/// Width of the lines in pixels, at every scale
+const LINE_PIXELS: f64 = 2.0;
+
+fn scale(scene: &Scene) -> f64 {
+    (MAX_PIXELS / scene.width.max(scene.height).max(1.0)).min(CELL_PIXELS)
+}
+
+fn size(scene: &Scene) -> (usize, usize) {
+    let scale = scale(scene);
+    ((scene.width * scale).ceil().max(1.0) as usize, (scene.height * scale).ceil().max(1.0) as usize)
+}
+
+fn hex(color: Rgb) -> String {
+    format!("#{:02x}{:02x}{:02x}", color.0, color.1, color.2)
+}
+
+/// The scene as an SVG document, as big as the PNG would be
+pub fn svg(scene: &Scene) -> String {
+    let (width, height) = size(scene);
+    let mut out = format!(
+        "<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"{}\" height=\"{}\" viewBox=\"0 0 {} {}\">\n",
+        width, height, scene.width, scene.height
+    );
+    out += &format!("<rect width=\"{}\" height=\"{}\" fill=\"{}\"/>\n", scene.width, scene.height, hex(scene.background));
+
+    if let Some(cells) = &scene.cells {
+        // One rectangle per run of equal cells in a row keeps the file small
+        out += "<g shape-rendering=\"crispEdges\">\n";
+        for (row, cells) in cells.rows().enumerate() {
+            let mut col = 0;
+            for run in cells.chunk_by(|a, b| a == b) {
+                if run[0] != scene.background {
+                    out += &format!(
+                        "<rect x=\"{}\" y=\"{}\" width=\"{}\" height=\"1\" fill=\"{}\"/>\n",
+                        col,
+                        row,
+                        run.len(),
+                        hex(run[0])
+                    );
+                }
+                col += run.len();
+            }
+        }
+        out += "</g>\n";
+    }
+
+    for shape in scene.shapes.iter() {
+        let points: Vec<String> = shape.points.iter().map(|(x, y)| format!("{},{}", x, y)).collect();
+        let (element, fill) = match (shape.closed, shape.fill) {
+            (true, Some(fill)) => ("polygon", hex(fill)),
+            (true, None) => ("polygon", "none".to_string()),
+            (false, _) => ("polyline", "none".to_string()),
+        };
+        out += &format!(
+            "<{} points=\"{}\" fill=\"{}\" stroke=\"{}\" stroke-width=\"{}\" stroke-linejoin=\"round\" vector-effect=\"non-scaling-stroke\"/>\n",
+            element,
+            points.join(" "),
+            fill,
+            hex(shape.color),
+            LINE_PIXELS
+        );
+    }
+    out += "</svg>\n";
+    out
+}
+
+struct Canvas {
+    width: usize,
+    height: usize,
+    pixels: Vec<u8>,
+}
+
+impl Canvas {
+    fn set(&mut self, x: i64, y: i64, color: Rgb) {
+        if x < 0 || y < 0 || x as usize >= self.width || y as usize >= self.height {
+            return;
+        }
+        let i = (y as usize * self.width + x as usize) * 3;
+        self.pixels[i..i + 3].copy_from_slice(&[color.0, color.1, color.2]);
+    }
+
+    // Pixels are filled when their center is inside
+    fn fill_rect(&mut self, x0: f64, y0: f64, x1: f64, y1: f64, color: Rgb) {
+        for y in (y0 - 0.5).ceil() as i64..(y1 - 0.5).ceil() as i64 {
+            for x in (x0 - 0.5).ceil() as i64..(x1 - 0.5).ceil() as i64 {
+                self.set(x, y, color);
+            }
+        }
+    }
+
+    // Scanlines through the pixel centers, filled between pairs of crossings (even-odd rule)
+    fn fill_polygon(&mut self, points: &[(f64, f64)], color: Rgb) {
+        for y in 0..self.height {
+            let center = y as f64 + 0.5;
+            let mut crossings: Vec<f64> = Vec::new();
+            for (i, &(x0, y0)) in points.iter().enumerate() {
+                let (x1, y1) = points[(i + 1) % points.len()];
+                if (y0 <= center) != (y1 <= center) {
+                    crossings.push(x0 + (center - y0) / (y1 - y0) * (x1 - x0));
+                }
+            }
+            crossings.sort_by(f64::total_cmp);
+            for pair in crossings.chunks_exact(2) {
+                self.fill_rect(pair[0], center - 0.5, pair[1], center + 0.5, color);
+            }
+        }
+    }
+
+    fn line(&mut self, (x0, y0): (f64, f64), (x1, y1): (f64, f64), color: Rgb) {
+        let steps = (x1 - x0).abs().max((y1 - y0).abs()).ceil().max(1.0) as usize;
+        let half = LINE_PIXELS / 2.0;
+        for i in 0..=steps {
+            let t = i as f64 / steps as f64;
+            let (x, y) = (x0 + t * (x1 - x0), y0 + t * (y1 - y0));
+            self.fill_rect(x - half, y - half, x + half, y + half, color);
+        }
+    }
+
+    fn shape(&mut self, shape: &Shape, scale: f64) {
+        let points: Vec<(f64, f64)> = shape.points.iter().map(|&(x, y)| (x * scale, y * scale)).collect();
+        if let Some(fill) = shape.fill {
+            self.fill_polygon(&points, fill);
+        }
+        for pair in points.windows(2) {
+            self.line(pair[0], pair[1], shape.color);
+        }
+        if shape.closed && points.len() > 2 {
+            self.line(points[points.len() - 1], points[0], shape.color);
+        }
+    }
+}
+
+/// The scene as a PNG image
+pub fn png(scene: &Scene) -> Result<Vec<u8>, String> {
+    let (width, height) = size(scene);
+    let scale = scale(scene);
+    let bg = scene.background;
+    let mut canvas = Canvas { width, height, pixels: [bg.0, bg.1, bg.2].repeat(width * height) };
+
+    if let Some(cells) = &scene.cells {
+        for (pos, &color) in cells.iter() {
+            if color != scene.background {
+                let (x, y) = (pos.col as f64 * scale, pos.row as f64 * scale);
+                canvas.fill_rect(x, y, x + scale, y + scale, color);
+            }
+        }
+    }
+    for shape in scene.shapes.iter() {
+        canvas.shape(shape, scale);
+    }
+
+    let mut out = Vec::new();
+    let mut encoder = png::Encoder::new(&mut out, width as u32, height as u32);
+    encoder.set_color(png::ColorType::Rgb);
+    encoder.set_depth(png::BitDepth::Eight);
+    let mut writer = encoder.write_header().map_err(|e| e.to_string())?;
+    writer.write_image_data(&canvas.pixels).map_err(|e| e.to_string())?;
+    writer.finish().map_err(|e| e.to_string())?;
+    Ok(out)
+}
+
+/// Writes an SVG or PNG file, depending on the extension of `path`
+pub fn save(scene: &Scene, path: &Path) -> Result<(), String> {
+    let data = match path.extension().and_then(|e| e.to_str()) {
+        Some("svg") => svg(scene).into_bytes(),
+        Some("png") => png(scene)?,
+        _ => return Err(format!("can't tell the image format of {}, use .svg or .png", path.display())),
+    };
+    write(path, data).map_err(|e| format!("could not write {}: {}", path.display(), e))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_common::Grid;
+
+    fn scene() -> Scene {
+        let cells = Grid::from_rows([[Rgb::BLACK, Rgb::BLACK, Rgb::WHITE], [Rgb::WHITE, Rgb::RED, Rgb::RED]]);
+        Scene::grid(cells).path([(0.5, 0.5), (2.5, 0.5)], Rgb::BLUE)
+    }
+
+    #[test]
+    fn svg_merges_cells() {
+        let svg = svg(&scene());
+        assert!(svg.starts_with("<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"24\" height=\"16\" viewBox=\"0 0 3 2\">"));
+        assert!(svg.contains("<rect x=\"0\" y=\"0\" width=\"2\" height=\"1\" fill=\"#000000\"/>"), "{}", svg);
+        assert!(svg.contains("<rect x=\"1\" y=\"1\" width=\"2\" height=\"1\" fill=\"#dc2828\"/>"), "{}", svg);
+        assert!(svg.contains("<polyline points=\"0.5,0.5 2.5,0.5\" fill=\"none\" stroke=\"#285adc\""), "{}", svg);
+    }
+
+    #[test]
+    fn png_pixels() {
+        let png = png(&scene()).unwrap();
+        let mut reader = png::Decoder::new(png.as_slice()).read_info().unwrap();
+        let mut pixels = vec![0; reader.output_buffer_size()];
+        let info = reader.next_frame(&mut pixels).unwrap();
+        assert_eq!((info.width, info.height), (24, 16));
+        let pixel = |x: usize, y: usize| Rgb(pixels[(y * 24 + x) * 3], pixels[(y * 24 + x) * 3 + 1], pixels[(y * 24 + x) * 3 + 2]);
+        assert_eq!(pixel(1, 1), Rgb::BLACK);
+        assert_eq!(pixel(20, 1), Rgb::WHITE);
+        assert_eq!(pixel(12, 12), Rgb::RED);
+        // The path through the middle of the top row
+        assert_eq!(pixel(12, 4), Rgb::BLUE);
+    }
+}
@@ -0,0 +1,167 @@
+use std::{
+    collections::HashMap,
+    io::{self, BufRead, Write},
+    sync::mpsc::{self, RecvTimeoutError},
+    thread,
+    time::Duration,
+};
+
+use aoc_common::{Color, Frame, Pos};
+use serde_json::json;
+
+const CLEAR: &str = "\x1b[H\x1b[2J";
+const RESET: &str = "\x1b[0m";
+
+// Black on a colored background, so highlighted empty cells stand out too
+fn escape(color: Color) -> &'static str {
+    match color {
+        Color::Red => "\x1b[30;41m",
+        Color::Green => "\x1b[30;42m",
+        Color::Yellow => "\x1b[30;43m",
+        Color::Blue => "\x1b[30;44m",
+        Color::Magenta => "\x1b[30;45m",
+        Color::Cyan => "\x1b[30;46m",
+    }
+}
+
+/// The grid followed by the text of a frame, one line each. Without `color` the
+/// highlights are left out.
+pub fn render(frame: &Frame, color: bool) -> String {
+    let mut out = String::new();
+    if let Some(grid) = &frame.grid {
+        let highlights: HashMap<_, _> = if color { frame.highlights.iter().copied().collect() } else { HashMap::new() };
+        for (row, cells) in grid.rows().enumerate() {
+            for (col, &c) in cells.iter().enumerate() {
+                match highlights.get(&Pos::new(row, col)) {
+                    Some(&color) => {
+                        out += escape(color);
+                        out.push(c);
+                        out += RESET;
+                    }
+                    None => out.push(c),
+                }
+            }
+            out.push('\n');
+        }
+    }
+    for line in frame.text.iter() {
+        out += line;
+        out.push('\n');
+    }
+    out
+}
+
+// Columns and lines a frame takes up in the terminal
+fn size(frame: &Frame) -> (usize, usize) {
+    let (grid_width, grid_height) = frame.grid.as_ref().map_or((0, 0), |g| (g.width(), g.height()));
+    let text_width = frame.text.iter().map(|l| l.chars().count()).max().unwrap_or(0);
+    (grid_width.max(text_width), grid_height + frame.text.len())
+}
+
+/// The frames as an asciicast (v2) recording, for `asciinema play` and the web player.
+/// The terminal is as big as the largest frame plus the status line.
+pub fn asciicast(frames: &[Frame], delay: Duration, color: bool) -> String {
+    let status_width = format!("frame {}/{}", frames.len(), frames.len()).len();
+    let (width, height) = frames.iter().map(size).fold((status_width, 0), |(w, h), (fw, fh)| (w.max(fw), h.max(fh)));
+    let mut out = json!({"version": 2, "width": width, "height": height + 1}).to_string();
+    out.push('\n');
+    for (i, frame) in frames.iter().enumerate() {
+        let time = delay.as_secs_f64() * i as f64;
+        let screen = format!("{}{}frame {}/{}", CLEAR, render(frame, color), i + 1, frames.len());
+        out += &json!([time, "o", screen.replace('\n', "\r\n")]).to_string();
+        out.push('\n');
+    }
+    out
+}
+
+/// Plays the frames in the terminal, showing the next one after `delay`. Controls are
+/// read from stdin: Enter pauses, while paused Enter steps forward, `b` back, `c`
+/// continues and `q` quits. Starts out paused with `paused`.
+pub fn play(frames: &[Frame], delay: Duration, color: bool, mut paused: bool) {
+    if frames.is_empty() {
+        return;
+    }
+
+    // Reading blocks, so it happens on its own thread. The channel closes when stdin does,
+    // then the frames just play through.
+    let (sender, commands) = mpsc::channel();
+    thread::spawn(move || {
+        for line in io::stdin().lock().lines() {
+            let Ok(line) = line else { break };
+            if sender.send(line.trim().to_string()).is_err() {
+                break;
+            }
+        }
+    });
+    let mut input_closed = false;
+
+    let mut i = 0;
+    loop {
+        let status = if paused {
+            "paused: Enter steps, b goes back, c continues, q quits"
+        } else {
+            "playing: Enter pauses, q quits"
+        };
+        print!("{}{}frame {}/{}, {}", CLEAR, render(&frames[i], color), i + 1, frames.len(), status);
+        // The cursor stays at the end of the status line, stdin is echoed there
+        let _ = io::stdout().flush();
+
+        if paused && !input_closed {
+            match commands.recv() {
+                Ok(command) => match command.as_str() {
+                    "q" => break,
+                    "c" => paused = false,
+                    "b" => i = i.saturating_sub(1),
+                    _ => i = (i + 1).min(frames.len() - 1),
+                },
+                Err(_) => input_closed = true,
+            }
+            continue;
+        }
+
+        if i + 1 == frames.len() {
+            break;
+        }
+        if input_closed {
+            thread::sleep(delay);
+        } else {
+            match commands.recv_timeout(delay) {
+                Ok(command) if command == "q" => break,
+                Ok(_) => {
+                    paused = true;
+                    continue;
+                }
+                Err(RecvTimeoutError::Timeout) => {}
+                Err(RecvTimeoutError::Disconnected) => input_closed = true,
+            }
+        }
+        i += 1;
+    }
+    println!();
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_common::Grid;
+
+    fn frame() -> Frame {
+        Frame::grid(Grid::from_lines(&["ab", "cd"], |c| c)).highlight(Pos::new(1, 0), Color::Red).line("two rows")
+    }
+
+    #[test]
+    fn highlights() {
+        assert_eq!(render(&frame(), false), "ab\ncd\ntwo rows\n");
+        assert_eq!(render(&frame(), true), "ab\n\x1b[30;41mc\x1b[0md\ntwo rows\n");
+    }
+
+    #[test]
+    fn asciicast_events() {
+        let cast = asciicast(&[frame(), Frame::text("done")], Duration::from_millis(500), false);
+        let lines: Vec<serde_json::Value> = cast.lines().map(|l| serde_json::from_str(l).unwrap()).collect();
+        assert_eq!(lines[0], json!({"version": 2, "width": 9, "height": 4}));
+        assert_eq!(lines[1], json!([0.0, "o", "\x1b[H\x1b[2Jab\r\ncd\r\ntwo rows\r\nframe 1/2"]));
+        assert_eq!(lines[2][0], json!(0.5));
+        assert_eq!(lines.len(), 3);
+    }
+}
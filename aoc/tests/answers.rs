@@ -0,0 +1,57 @@
+use std::{fs::read_to_string, path::PathBuf};
+
+use aoc::{answers::Store, days, demos::{self, DEMOS}};
+use aoc_common::Params;
+
+fn root() -> PathBuf {
+    PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("..")
+}
+
+// Runs one part and returns a description of the problem if the answer does not match
+fn check(day: u8, part: u8, input_path: &PathBuf, params: &Params, expected: &str) -> Option<String> {
+    let input = read_to_string(input_path).unwrap();
+    let (_, answer) = match days::run(day, &input, Some(part), params) {
+        Ok(mut answers) => answers.pop().unwrap(),
+        Err(e) => return Some(e),
+    };
+    let answer = answer.to_string();
+    if answer != expected {
+        Some(format!("day {:02} part {} on {}: expected {}, got {}", day, part, input_path.display(), expected, answer))
+    } else {
+        None
+    }
+}
+
+fn assert_all(failures: Vec<String>) {
+    assert!(failures.is_empty(), "\n{}", failures.join("\n"));
+}
+
+#[test]
+fn demos() {
+    let mut failures = Vec::new();
+    for (day, file, part1, part2, overrides) in DEMOS {
+        let input_path = root().join(format!("{:02}", day)).join(file);
+        let params = demos::params(overrides);
+        for (part, expected) in [(1, part1), (2, part2)] {
+            if let Some(expected) = expected {
+                failures.extend(check(*day, part, &input_path, &params, expected));
+            }
+        }
+    }
+    assert_all(failures);
+}
+
+// The real inputs and their answers are private, so they are only checked for the
+// confirmed answers in `answers.toml` in the workspace root (see `aoc::answers::Store`,
+// `aoc answer` records them). Each day is run on `<day>/input.txt`.
+#[test]
+fn real_inputs() {
+    let store = Store::load(&root().join("answers.toml")).unwrap();
+
+    let mut failures = Vec::new();
+    for (day, part, expected) in store.confirmed() {
+        let input_path = root().join(format!("{:02}", day)).join("input.txt");
+        failures.extend(check(day, part, &input_path, &Params::new(), expected));
+    }
+    assert_all(failures);
+}
@@ -0,0 +1,101 @@
+use std::{
+    fs::read_to_string,
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        Arc,
+    },
+    thread,
+    time::{Duration, Instant},
+};
+
+use aoc::fetch::{Config, Fetcher, Ureq};
+use tiny_http::{Response, Server};
+
+// Answers every request with the day's fake input, as long as the session cookie is right
+fn stub_server() -> (String, Arc<AtomicUsize>) {
+    let server = Server::http("127.0.0.1:0").unwrap();
+    let url = format!("http://{}", server.server_addr().to_ip().unwrap());
+    let requests = Arc::new(AtomicUsize::new(0));
+    let counter = requests.clone();
+    thread::spawn(move || {
+        for request in server.incoming_requests() {
+            counter.fetch_add(1, Ordering::SeqCst);
+            let authorized = request
+                .headers()
+                .iter()
+                .any(|h| h.field.equiv("Cookie") && h.value.as_str() == "session=secret");
+            let response = match request.url().strip_prefix("/2023/day/").and_then(|r| r.strip_suffix("/input")) {
+                Some(day) if authorized => Response::from_string(format!("input of day {}\n", day)),
+                Some(_) => Response::from_string("Puzzle inputs differ by user.").with_status_code(400),
+                None => Response::from_string("not found").with_status_code(404),
+            };
+            request.respond(response).unwrap();
+        }
+    });
+    (url, requests)
+}
+
+fn config(url: String, session: &str, min_interval: Duration) -> (Config, tempfile::TempDir) {
+    let cache = tempfile::tempdir().unwrap();
+    let config = Config {
+        session: Some(session.to_string()),
+        url,
+        cache_dir: cache.path().to_path_buf(),
+        min_interval,
+    };
+    (config, cache)
+}
+
+#[test]
+fn downloads_once() {
+    let (url, requests) = stub_server();
+    let (config, _cache) = config(url, "secret", Duration::ZERO);
+    let fetcher = Fetcher::new(config, Ureq);
+
+    let (path, downloaded) = fetcher.fetch(5).unwrap();
+    assert!(downloaded);
+    assert_eq!(read_to_string(&path).unwrap(), "input of day 5\n");
+
+    let (again, downloaded) = fetcher.fetch(5).unwrap();
+    assert!(!downloaded);
+    assert_eq!(again, path);
+    assert_eq!(requests.load(Ordering::SeqCst), 1);
+}
+
+#[test]
+fn rate_limited() {
+    let (url, requests) = stub_server();
+    let (config, _cache) = config(url, "secret", Duration::from_millis(300));
+    let fetcher = Fetcher::new(config, Ureq);
+
+    let start = Instant::now();
+    for day in 1..=3 {
+        fetcher.fetch(day).unwrap();
+    }
+    assert!(start.elapsed() >= Duration::from_millis(600), "took {:?}", start.elapsed());
+    assert_eq!(requests.load(Ordering::SeqCst), 3);
+}
+
+#[test]
+fn bad_session_is_not_cached() {
+    let (url, requests) = stub_server();
+    let (config, cache) = config(url, "expired", Duration::ZERO);
+    let fetcher = Fetcher::new(config, Ureq);
+
+    let err = fetcher.fetch(7).unwrap_err();
+    assert!(err.contains("session token"), "{}", err);
+    assert!(!cache.path().join("07.txt").exists());
+    fetcher.fetch(7).unwrap_err();
+    assert_eq!(requests.load(Ordering::SeqCst), 2);
+}
+
+#[test]
+fn config_file() {
+    let config = Config::parse("session = \"abc\"\nurl = \"http://localhost:8080/\"\nmin_interval = 0.5\n").unwrap();
+    assert_eq!(config.session.as_deref(), Some("abc"));
+    assert_eq!(config.url, "http://localhost:8080");
+    assert_eq!(config.min_interval, Duration::from_millis(500));
+
+    assert!(Config::parse("sesion = \"abc\"").unwrap_err().contains("unknown setting"));
+    assert!(Config::parse("min_interval = \"5\"").unwrap_err().contains("invalid value"));
+}
@@ -0,0 +1,25 @@
+use std::panic::{self, AssertUnwindSafe};
+
+use aoc::days;
+use aoc_common::Params;
+
+// Small enough for the slow days to stay quick in debug builds
+const SIZES: [usize; 3] = [5, 12, 20];
+
+#[test]
+fn generated_inputs_are_solved() {
+    let mut failures = Vec::new();
+    for day in 1..=25 {
+        for (seed, &size) in SIZES.iter().enumerate() {
+            let input = days::generate(day, seed as u64, size);
+            assert_eq!(input, days::generate(day, seed as u64, size), "day {:02} is not seeded", day);
+            let result = panic::catch_unwind(AssertUnwindSafe(|| days::run(day, &input, None, &Params::new())));
+            match result {
+                Ok(Ok(_)) => {}
+                Ok(Err(e)) => failures.push(format!("{} with seed {} and size {}", e, seed, size)),
+                Err(_) => failures.push(format!("day {:02} panicked with seed {} and size {}:\n{}", day, seed, size, input)),
+            }
+        }
+    }
+    assert!(failures.is_empty(), "\n{}", failures.join("\n"));
+}
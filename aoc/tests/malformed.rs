@@ -0,0 +1,88 @@
+use std::{fs::read_to_string, panic, path::PathBuf};
+
+use aoc::{days, demos::DEMOS};
+use aoc_common::{Params, Rng};
+
+const MUTANTS: usize = 1000;
+
+// Characters the parsers split on or expect, and some they don't
+const PIECES: &[&str] = &[
+    "", " ", "\n", "\n\n", "\r\n", ",", ":", ": ", ";", "=", "-", "->", "@", "~", "{", "}", "(", ")", "#", ".", "?", "S",
+    "0", "1", "9", "-1", "99999999999999999999999", "18446744073709551615", "9223372036854775807", "-9223372036854775808",
+    "A", "Z", "x", "L", "R", "é", "Game", "Card", "seeds:", "%", "&",
+];
+
+// Inputs that made a parser or a part panic once, both parts run on them
+const REGRESSIONS: &[(u8, &str)] = &[
+    (2, "Game 1: 18446744073709551615 red, 1 red\n"),
+    (2, "Game 1: 4294967296 red, 4294967296 blue, 4294967296 green\n"),
+    (3, "99999999999*99999999999\n"),
+    (5, "seeds: 1 2\n\nx map:\n18446744073709551615 18446744073709551615 5\n"),
+    (5, "seeds: 1\n\nx map:\n1 2 3\n"),
+    (5, "seeds: 18446744073709551615 5\n\nx map:\n1 2 3\n"),
+    (4, "Card 1: 1 | 1\n"),
+    (7, "32T3K 765\n 483\n"),
+    (8, "\n\nAAA = (AAA, AAA)\n"),
+    (8, "L\n\nAAA = (BBB, BBB)\n"),
+    (9, "1\n"),
+    (9, "10 13 16 21 30 5\n"),
+    (10, "-L|F7S"),
+    (12, "???.### 1,9223372036854775807,3\n"),
+    (16, ""),
+    (17, "2"),
+    (18, "R 6 (#70c710)"),
+    (19, "in{x<5:in,A}\n\n{x=1,m=2,a=3,s=4}\n"),
+    (19, "in{x<5:ab,A}\n\n{x=1,m=2,a=3,s=4}\n"),
+    (20, "broadcaster -> a\n"),
+    (23, "."),
+    (25, "a: b"),
+];
+
+// A demo with a few random edits: characters replaced, cut or repeated, and pieces
+// inserted
+fn mutate(rng: &mut Rng, demo: &str) -> String {
+    let mut chars: Vec<String> = demo.chars().map(String::from).collect();
+    for _ in 0..1 + rng.below(4) {
+        let at = rng.below(chars.len() + 1);
+        match rng.below(5) {
+            0 if at < chars.len() => chars[at] = rng.pick(PIECES).to_string(),
+            1 => chars.truncate(at),
+            2 if at < chars.len() => {
+                let end = (at + rng.below(40)).min(chars.len());
+                chars.drain(at..end);
+            }
+            3 if at < chars.len() => {
+                let end = (at + rng.below(40)).min(chars.len());
+                let repeated: Vec<String> = chars[at..end].to_vec();
+                chars.splice(at..at, repeated);
+            }
+            _ => chars.insert(at, rng.pick(PIECES).to_string()),
+        }
+    }
+    chars.concat()
+}
+
+// What the fuzz targets in `fuzz/` check, in a form that runs on stable: parsing
+// malformed input returns an error instead of panicking
+#[test]
+fn parsers_reject_malformed_input() {
+    let root = PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("..");
+    let mut failures = Vec::new();
+    for (day, input) in REGRESSIONS {
+        if panic::catch_unwind(|| days::run(*day, input, None, &Params::new())).is_err() {
+            failures.push(format!("day {:02} panicked on {:?}", day, input));
+        }
+    }
+    for (i, (day, file, ..)) in DEMOS.iter().enumerate() {
+        let demo = read_to_string(root.join(format!("{:02}", day)).join(file)).unwrap();
+        let mut rng = Rng::new(i as u64);
+        for _ in 0..MUTANTS {
+            let input = mutate(&mut rng, &demo);
+            if panic::catch_unwind(|| days::get(*day)(&input)).is_err() {
+                failures.push(format!("day {:02} panicked on {:?}", day, input));
+                break;
+            }
+        }
+    }
+    assert!(failures.is_empty(), "\n{}", failures.join("\n"));
+}
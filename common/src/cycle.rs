@@ -0,0 +1,133 @@
+use std::{
+    collections::hash_map::DefaultHasher,
+    hash::{Hash, Hasher},
+};
+
+/// Where the states `x, f(x), f(f(x)), ...` start repeating: from the state after `start`
+/// steps on, every state comes back after `length` more steps
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Cycle {
+    pub start: usize,
+    pub length: usize,
+}
+
+impl Cycle {
+    /// The first step with the same state as step `n`
+    pub fn reduce(&self, n: usize) -> usize {
+        if n < self.start {
+            n
+        } else {
+            self.start + (n - self.start) % self.length
+        }
+    }
+}
+
+fn fingerprint<S: Hash>(state: &S) -> u64 {
+    let mut hasher = DefaultHasher::new();
+    state.hash(&mut hasher);
+    hasher.finish()
+}
+
+/// Brent's cycle detection. Only two states are alive at a time and they are compared by
+/// their 64 bit hashes, a collision would report a wrong cycle.
+pub fn brent<S: Hash + Clone>(initial: &S, mut step: impl FnMut(&S) -> S) -> Cycle {
+    // The hare runs ahead and the tortoise teleports to it at every power of two, they
+    // meet once the power is at least the length
+    let mut power = 1;
+    let mut length = 1;
+    let mut tortoise = fingerprint(initial);
+    let mut hare = step(initial);
+    while fingerprint(&hare) != tortoise {
+        if power == length {
+            tortoise = fingerprint(&hare);
+            power *= 2;
+            length = 0;
+        }
+        hare = step(&hare);
+        length += 1;
+    }
+
+    // With the hare `length` steps ahead, both meet where the cycle starts
+    let mut tortoise = initial.clone();
+    let mut hare = initial.clone();
+    for _ in 0..length {
+        hare = step(&hare);
+    }
+    let mut start = 0;
+    while fingerprint(&tortoise) != fingerprint(&hare) {
+        tortoise = step(&tortoise);
+        hare = step(&hare);
+        start += 1;
+    }
+
+    Cycle { start, length }
+}
+
+/// The state after `n` steps, without going around the cycle more than once
+pub fn nth<S: Hash + Clone>(initial: &S, mut step: impl FnMut(&S) -> S, n: usize) -> S {
+    let cycle = brent(initial, &mut step);
+    let mut state = initial.clone();
+    for _ in 0..cycle.reduce(n) {
+        state = step(&state);
+    }
+    state
+}
+
+// (g, x, y) with a * x + b * y = g = gcd(a, b)
+fn extended_gcd(a: i128, b: i128) -> (i128, i128, i128) {
+    if b == 0 {
+        (a, 1, 0)
+    } else {
+        let (g, x, y) = extended_gcd(b, a % b);
+        (g, y, x - a / b * y)
+    }
+}
+
+/// Combines congruences `x ≡ residue (mod modulus)` into one: the smallest non-negative
+/// solution and the least common multiple of the moduli. The moduli don't have to be
+/// coprime, None if the congruences contradict each other.
+pub fn crt(congruences: impl IntoIterator<Item = (i128, i128)>) -> Option<(i128, i128)> {
+    let mut combined = (0, 1);
+    for (residue, modulus) in congruences {
+        let (r, m) = combined;
+        let (g, inverse, _) = extended_gcd(m, modulus);
+        if (residue - r) % g != 0 {
+            return None;
+        }
+        // r + m * k solves both for m * k ≡ residue - r (mod modulus)
+        let k = (residue - r) / g * inverse % (modulus / g);
+        let lcm = m / g * modulus;
+        combined = ((r + m * k).rem_euclid(lcm), lcm);
+    }
+    Some(combined)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // 0, 1, ..., 10, 3, 4, ...
+    fn step(&x: &usize) -> usize {
+        if x < 10 {
+            x + 1
+        } else {
+            3
+        }
+    }
+
+    #[test]
+    fn finds_cycles() {
+        assert_eq!(brent(&0, step), Cycle { start: 3, length: 8 });
+        assert_eq!(brent(&5, step), Cycle { start: 0, length: 8 });
+        assert_eq!(nth(&0, step, 2), 2);
+        assert_eq!(nth(&0, step, 1_000_000_000), 3 + (1_000_000_000 - 3) % 8);
+    }
+
+    #[test]
+    fn chinese_remainders() {
+        assert_eq!(crt([(2, 3), (3, 5), (2, 7)]), Some((23, 105)));
+        assert_eq!(crt([(3, 4), (1, 6)]), Some((7, 12)));
+        assert_eq!(crt([(0, 4), (1, 6)]), None);
+        assert_eq!(crt([(-1, 5), (14, 10)]), Some((4, 10)));
+    }
+}
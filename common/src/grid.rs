@@ -0,0 +1,270 @@
+use std::{
+    fmt::{self, Display},
+    ops::{Index, IndexMut},
+};
+
+/// A cell of a grid, row 0 is at the top
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Pos {
+    pub row: usize,
+    pub col: usize,
+}
+
+impl Pos {
+    pub fn new(row: usize, col: usize) -> Self {
+        Pos { row, col }
+    }
+
+    pub fn manhattan(self, other: Pos) -> usize {
+        self.row.abs_diff(other.row) + self.col.abs_diff(other.col)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Direction {
+    Up,
+    Down,
+    Left,
+    Right,
+}
+
+impl Direction {
+    pub const ALL: [Direction; 4] = [Direction::Up, Direction::Down, Direction::Left, Direction::Right];
+
+    pub fn opposite(self) -> Direction {
+        match self {
+            Direction::Up => Direction::Down,
+            Direction::Down => Direction::Up,
+            Direction::Left => Direction::Right,
+            Direction::Right => Direction::Left,
+        }
+    }
+
+    pub fn turn_left(self) -> Direction {
+        match self {
+            Direction::Up => Direction::Left,
+            Direction::Left => Direction::Down,
+            Direction::Down => Direction::Right,
+            Direction::Right => Direction::Up,
+        }
+    }
+
+    pub fn turn_right(self) -> Direction {
+        self.turn_left().opposite()
+    }
+
+    /// (row, column) difference of one step
+    pub fn offset(self) -> (isize, isize) {
+        match self {
+            Direction::Up => (-1, 0),
+            Direction::Down => (1, 0),
+            Direction::Left => (0, -1),
+            Direction::Right => (0, 1),
+        }
+    }
+}
+
+const OFFSETS_8: [(isize, isize); 8] = [(-1, -1), (-1, 0), (-1, 1), (0, -1), (0, 1), (1, -1), (1, 0), (1, 1)];
+
+/// A rectangular grid, stored row by row
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+impl<T> Grid<T> {
+    pub fn new(width: usize, height: usize, fill: T) -> Self
+    where
+        T: Clone,
+    {
+        Grid { width, height, cells: vec![fill; width * height] }
+    }
+
+    /// Panics if the rows don't all have the same length
+    pub fn from_rows<R: IntoIterator<Item = T>>(rows: impl IntoIterator<Item = R>) -> Self {
+        let mut cells = Vec::new();
+        let mut width = None;
+        let mut height = 0;
+        for row in rows {
+            let before = cells.len();
+            cells.extend(row);
+            let row_width = cells.len() - before;
+            assert_eq!(*width.get_or_insert(row_width), row_width, "row {} has a different width", height);
+            height += 1;
+        }
+        Grid { width: width.unwrap_or(0), height, cells }
+    }
+
+    /// One cell per character, for the lines returned by `Source::grid`
+    pub fn from_lines(lines: &[&str], cell: impl Fn(char) -> T) -> Self {
+        Grid::from_rows(lines.iter().map(|line| line.chars().map(&cell)))
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn get(&self, pos: Pos) -> Option<&T> {
+        self.contains(pos).then(|| &self.cells[pos.row * self.width + pos.col])
+    }
+
+    pub fn get_mut(&mut self, pos: Pos) -> Option<&mut T> {
+        if self.contains(pos) {
+            Some(&mut self.cells[pos.row * self.width + pos.col])
+        } else {
+            None
+        }
+    }
+
+    pub fn contains(&self, pos: Pos) -> bool {
+        pos.row < self.height && pos.col < self.width
+    }
+
+    /// `pos` moved by `offset`, if that is still inside the grid
+    pub fn offset(&self, pos: Pos, offset: (isize, isize)) -> Option<Pos> {
+        let row = pos.row.checked_add_signed(offset.0)?;
+        let col = pos.col.checked_add_signed(offset.1)?;
+        Some(Pos::new(row, col)).filter(|&p| self.contains(p))
+    }
+
+    /// The neighbour of `pos` in `direction`, if there is one
+    pub fn step(&self, pos: Pos, direction: Direction) -> Option<Pos> {
+        self.offset(pos, direction.offset())
+    }
+
+    /// The cell a position on the grid repeated infinitely in all directions maps to
+    pub fn wrap(&self, row: isize, col: isize) -> Pos {
+        Pos::new(row.rem_euclid(self.height as isize) as usize, col.rem_euclid(self.width as isize) as usize)
+    }
+
+    pub fn get_wrapped(&self, row: isize, col: isize) -> &T {
+        &self[self.wrap(row, col)]
+    }
+
+    /// Up, down, left and right neighbours inside the grid
+    pub fn neighbors4(&self, pos: Pos) -> impl Iterator<Item = Pos> + '_ {
+        Direction::ALL.into_iter().filter_map(move |d| self.step(pos, d))
+    }
+
+    /// Neighbours inside the grid including the diagonal ones, row by row
+    pub fn neighbors8(&self, pos: Pos) -> impl Iterator<Item = Pos> + '_ {
+        OFFSETS_8.into_iter().filter_map(move |o| self.offset(pos, o))
+    }
+
+    /// All positions, row by row
+    pub fn positions(&self) -> impl Iterator<Item = Pos> {
+        let width = self.width;
+        (0..self.height).flat_map(move |row| (0..width).map(move |col| Pos::new(row, col)))
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (Pos, &T)> {
+        self.positions().zip(self.cells.iter())
+    }
+
+    /// The first position (row by row) whose cell matches
+    pub fn position(&self, pred: impl Fn(&T) -> bool) -> Option<Pos> {
+        self.iter().find(|(_, c)| pred(c)).map(|(p, _)| p)
+    }
+
+    pub fn row(&self, row: usize) -> &[T] {
+        &self.cells[row * self.width..(row + 1) * self.width]
+    }
+
+    pub fn rows(&self) -> impl DoubleEndedIterator<Item = &[T]> {
+        // chunks would panic on a zero width
+        (0..self.height).map(|row| self.row(row))
+    }
+
+    pub fn column(&self, col: usize) -> impl DoubleEndedIterator<Item = &T> {
+        (0..self.height).map(move |row| &self.cells[row * self.width + col])
+    }
+
+    pub fn map<U>(&self, f: impl Fn(&T) -> U) -> Grid<U> {
+        Grid { width: self.width, height: self.height, cells: self.cells.iter().map(f).collect() }
+    }
+}
+
+impl<T: Clone> Grid<T> {
+    /// Rows become columns
+    pub fn transpose(&self) -> Grid<T> {
+        Grid::from_rows((0..self.width).map(|col| self.column(col).cloned()))
+    }
+
+    /// Rotated by 90 degrees clockwise, the left column becomes the top row
+    pub fn rotate_cw(&self) -> Grid<T> {
+        Grid::from_rows((0..self.width).map(|col| self.column(col).rev().cloned()))
+    }
+
+    /// Rotated by 90 degrees counterclockwise, the right column becomes the top row
+    pub fn rotate_ccw(&self) -> Grid<T> {
+        Grid::from_rows((0..self.width).rev().map(|col| self.column(col).cloned()))
+    }
+}
+
+impl<T> Index<Pos> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, pos: Pos) -> &T {
+        self.get(pos).unwrap_or_else(|| panic!("{:?} is outside of the {}x{} grid", pos, self.width, self.height))
+    }
+}
+
+impl<T> IndexMut<Pos> for Grid<T> {
+    fn index_mut(&mut self, pos: Pos) -> &mut T {
+        let (width, height) = (self.width, self.height);
+        self.get_mut(pos).unwrap_or_else(|| panic!("{:?} is outside of the {}x{} grid", pos, width, height))
+    }
+}
+
+impl<T: Display> Display for Grid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for row in self.rows() {
+            for cell in row {
+                write!(f, "{}", cell)?;
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn grid() -> Grid<char> {
+        Grid::from_lines(&["abc", "def"], |c| c)
+    }
+
+    #[test]
+    fn neighbors_stay_inside() {
+        let grid = grid();
+        let corner: Vec<char> = grid.neighbors4(Pos::new(0, 0)).map(|p| grid[p]).collect();
+        assert_eq!(corner, ['d', 'b']);
+        let middle: String = grid.neighbors8(Pos::new(1, 1)).map(|p| grid[p]).collect();
+        assert_eq!(middle, "abcdf");
+        assert_eq!(grid.step(Pos::new(1, 2), Direction::Right), None);
+    }
+
+    #[test]
+    fn wrapping() {
+        let grid = grid();
+        assert_eq!(*grid.get_wrapped(-1, -1), 'f');
+        assert_eq!(*grid.get_wrapped(2, 7), 'b');
+    }
+
+    #[test]
+    fn rotations() {
+        let grid = grid();
+        assert_eq!(grid.transpose().to_string(), "ad\nbe\ncf\n");
+        assert_eq!(grid.rotate_cw().to_string(), "da\neb\nfc\n");
+        assert_eq!(grid.rotate_ccw().to_string(), "cf\nbe\nad\n");
+        assert_eq!(grid.rotate_cw().rotate_cw().rotate_cw().rotate_cw(), grid);
+    }
+}
@@ -0,0 +1,53 @@
+mod answer;
+pub mod cycle;
+mod grid;
+mod params;
+mod parse;
+mod rng;
+mod scene;
+mod vis;
+
+pub use answer::Answer;
+pub use grid::{Direction, Grid, Pos};
+pub use params::{Param, Params};
+pub use parse::{ParseError, Source};
+pub use rng::Rng;
+pub use scene::{Rgb, Scene, Shape};
+pub use vis::{Color, Frame, Recording};
+
+// Every day implements this, the runner only talks to the days through it
+pub trait Solution {
+    type Input;
+
+    /// What can be changed with `--param`, mostly to run the examples
+    const PARAMS: &'static [Param] = &[];
+
+    /// Fails for parameter values the parts can't run with, before anything is parsed
+    fn check_params(_params: &Params) -> Result<(), String> {
+        Ok(())
+    }
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError>;
+
+    // The parts fail for inputs that parse but have no answer, like a day 01 line
+    // without a digit
+    fn part1(input: &Self::Input, params: &Params) -> Result<Answer, String>;
+    fn part2(input: &Self::Input, params: &Params) -> Result<Answer, String>;
+
+    /// Pushes frames showing how the solution works, for `aoc vis`
+    fn visualize(_input: &Self::Input, _params: &Params, _recording: &mut Recording) {}
+
+    /// A picture of the answer of `part`, for `aoc run --render`
+    fn render(_input: &Self::Input, _params: &Params, _part: u8) -> Option<Scene> {
+        None
+    }
+
+    /// How the answer of `part` comes about, for `aoc run --explain`
+    fn explain(_input: &Self::Input, _params: &Params, _part: u8) -> Option<String> {
+        None
+    }
+
+    /// A random input that both parts can solve with the default parameters, for `aoc gen`.
+    /// What `size` counts depends on the day: lines, the side of a grid, ...
+    fn generate(rng: &mut Rng, size: usize) -> String;
+}
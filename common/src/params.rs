@@ -0,0 +1,83 @@
+use std::collections::BTreeMap;
+
+/// A value a solution depends on that differs between the puzzle and its examples
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Param {
+    pub name: &'static str,
+    /// The value for the real puzzle
+    pub default: i64,
+    pub help: &'static str,
+}
+
+/// Values overriding the defaults of some parameters
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Params {
+    values: BTreeMap<String, i64>,
+}
+
+impl Params {
+    pub fn new() -> Self {
+        Params::default()
+    }
+
+    pub fn set(&mut self, name: &str, value: i64) {
+        self.values.insert(name.to_string(), value);
+    }
+
+    /// Parses `name=value`
+    pub fn set_from_str(&mut self, assignment: &str) -> Result<(), String> {
+        let (name, value) = assignment
+            .split_once('=')
+            .ok_or_else(|| format!("expected `name=value`, found `{}`", assignment))?;
+        let value = value.trim().replace('_', "");
+        let value = value.parse().map_err(|_| format!("`{}` is not a whole number", value))?;
+        self.set(name.trim(), value);
+        Ok(())
+    }
+
+    pub fn get(&self, param: &Param) -> i64 {
+        self.values.get(param.name).copied().unwrap_or(param.default)
+    }
+
+    /// For parameters that count something, fails for negative values
+    pub fn get_usize(&self, param: &Param) -> Result<usize, String> {
+        let value = self.get(param);
+        usize::try_from(value).map_err(|_| format!("{} can't be negative, it is {}", param.name, value))
+    }
+
+    /// Fails for overrides that aren't in `known`
+    pub fn check(&self, known: &[Param]) -> Result<(), String> {
+        for name in self.values.keys() {
+            if !known.iter().any(|p| p.name == name) {
+                let names: Vec<&str> = known.iter().map(|p| p.name).collect();
+                if names.is_empty() {
+                    return Err(format!("unknown parameter `{}`, there are none", name));
+                }
+                return Err(format!("unknown parameter `{}`, expected one of {}", name, names.join(", ")));
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const STEPS: Param = Param { name: "steps", default: 64, help: "" };
+
+    #[test]
+    fn overrides() {
+        let mut params = Params::new();
+        assert_eq!(params.get(&STEPS), 64);
+        params.set_from_str("steps=1_000").unwrap();
+        assert_eq!(params.get(&STEPS), 1000);
+        assert!(params.check(&[STEPS]).is_ok());
+        assert_eq!(params.check(&[]).unwrap_err(), "unknown parameter `steps`, there are none");
+        assert!(params.set_from_str("steps").is_err());
+        assert!(params.set_from_str("steps=x").is_err());
+        assert_eq!(params.get_usize(&STEPS), Ok(1000));
+        params.set_from_str("steps=-1").unwrap();
+        assert_eq!(params.get_usize(&STEPS).unwrap_err(), "steps can't be negative, it is -1");
+    }
+}
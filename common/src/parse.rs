@@ -0,0 +1,189 @@
+use std::{
+    error::Error,
+    fmt::{self, Display},
+    str::FromStr,
+};
+
+/// Where and why an input could not be parsed
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    /// Filled in by the runner, the days themselves don't know their number
+    pub day: Option<u8>,
+    /// 1-based
+    pub line: usize,
+    /// 1-based, counted in characters
+    pub column: usize,
+    pub expected: String,
+    pub found: String,
+    /// The offending line, without its line ending
+    pub text: String,
+    windows_line_endings: bool,
+}
+
+impl ParseError {
+    pub fn on_day(mut self, day: u8) -> Self {
+        self.day = Some(day);
+        self
+    }
+}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if let Some(day) = self.day {
+            write!(f, "day {:02}, ", day)?;
+        }
+        writeln!(f, "line {}, column {}: expected {}, found {}", self.line, self.column, self.expected, self.found)?;
+
+        let gutter = " ".repeat(self.line.to_string().len());
+        writeln!(f, "{} |", gutter)?;
+        writeln!(f, "{} | {}", self.line, self.text)?;
+        write!(f, "{} | {}^", gutter, " ".repeat(self.column - 1))?;
+
+        if self.windows_line_endings {
+            write!(f, "\n{} = note: the input has Windows line endings", gutter)?;
+        }
+        Ok(())
+    }
+}
+
+impl Error for ParseError {}
+
+/// The whole input of a day, used to turn slices of it into error locations.
+///
+/// All the helpers take slices of the input they were created from, an error points at
+/// the place in the input where the slice starts (or ends, when something is missing at
+/// the end of it).
+#[derive(Clone, Copy)]
+pub struct Source<'a> {
+    text: &'a str,
+}
+
+impl<'a> Source<'a> {
+    pub fn new(text: &'a str) -> Self {
+        Source { text }
+    }
+
+    pub fn lines(&self) -> std::str::Lines<'a> {
+        self.text.lines()
+    }
+
+    /// Error located at the start of `at`, which has to be a slice of the input.
+    /// Anything else is reported at the end of the input.
+    pub fn error(&self, at: &str, expected: impl Into<String>) -> ParseError {
+        let start = self.text.as_ptr() as usize;
+        let offset = (at.as_ptr() as usize)
+            .checked_sub(start)
+            .filter(|&o| o <= self.text.len())
+            .unwrap_or(self.text.len());
+
+        let line_start = self.text[..offset].rfind('\n').map_or(0, |i| i + 1);
+        let line_end = self.text[offset..].find('\n').map_or(self.text.len(), |i| offset + i);
+        let raw_line = &self.text[line_start..line_end];
+
+        let found = match self.text[offset..].chars().next() {
+            None => "end of input".to_string(),
+            Some('\n') | Some('\r') => "end of line".to_string(),
+            Some(c) => format!("`{}`", c),
+        };
+
+        ParseError {
+            day: None,
+            line: self.text[..offset].matches('\n').count() + 1,
+            column: self.text[line_start..offset].chars().count() + 1,
+            expected: expected.into(),
+            found,
+            text: raw_line.trim_end_matches('\r').to_string(),
+            windows_line_endings: self.text.contains('\r'),
+        }
+    }
+
+    /// Error located right after `after`
+    pub fn error_after(&self, after: &str, expected: impl Into<String>) -> ParseError {
+        self.error(&after[after.len()..], expected)
+    }
+
+    pub fn split_once(&self, s: &'a str, delimiter: &str) -> Result<(&'a str, &'a str), ParseError> {
+        s.split_once(delimiter).ok_or_else(|| self.error_after(s, format!("`{}`", delimiter.escape_debug())))
+    }
+
+    pub fn strip_prefix(&self, s: &'a str, prefix: &str) -> Result<&'a str, ParseError> {
+        s.strip_prefix(prefix).ok_or_else(|| self.error(s, format!("`{}`", prefix.escape_debug())))
+    }
+
+    pub fn strip_suffix(&self, s: &'a str, suffix: &str) -> Result<&'a str, ParseError> {
+        s.strip_suffix(suffix).ok_or_else(|| self.error_after(s, format!("`{}`", suffix.escape_debug())))
+    }
+
+    /// The non-empty lines of `s`, checked to all have the same width and to only contain
+    /// characters accepted by `valid` (`expected` describes those)
+    pub fn grid(&self, s: &'a str, valid: impl Fn(char) -> bool, expected: &str) -> Result<Vec<&'a str>, ParseError> {
+        let lines: Vec<&'a str> = s.lines().map(|l| l.trim_end_matches('\r')).filter(|l| !l.is_empty()).collect();
+        let width = lines.first().map_or(0, |l| l.len());
+        for line in lines.iter() {
+            if let Some((i, _)) = line.char_indices().find(|(_, c)| !valid(*c)) {
+                return Err(self.error(&line[i..], expected));
+            }
+            if line.len() < width {
+                return Err(self.error_after(line, expected));
+            }
+            if line.len() > width {
+                return Err(self.error(&line[width..], "end of line"));
+            }
+        }
+        Ok(lines)
+    }
+
+    pub fn number<T: FromStr>(&self, s: &'a str) -> Result<T, ParseError> {
+        s.parse().map_err(|_| self.error(s, "a number"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn points_at_the_slice() {
+        let input = "Game 1: 3 blue\nGame 2: 4 purple\n";
+        let source = Source::new(input);
+        let (_, rest) = input.split_once("4 ").unwrap();
+        let err = source.error(&rest[..6], "a color");
+        assert_eq!((err.line, err.column), (2, 11));
+        assert_eq!(err.found, "`p`");
+        assert_eq!(err.text, "Game 2: 4 purple");
+        assert_eq!(
+            err.on_day(2).to_string(),
+            "day 02, line 2, column 11: expected a color, found `p`\n  |\n2 | Game 2: 4 purple\n  |           ^"
+        );
+    }
+
+    #[test]
+    fn truncated_input() {
+        let input = "Game 1: 3 blue\nGame 2";
+        let source = Source::new(input);
+        let last = input.lines().last().unwrap();
+        let err = source.split_once(last, ": ").unwrap_err();
+        assert_eq!((err.line, err.column), (2, 7));
+        assert_eq!(err.expected, "`: `");
+        assert_eq!(err.found, "end of input");
+    }
+
+    #[test]
+    fn windows_line_endings() {
+        let input = "1,2\r\n3,x\r\n";
+        let source = Source::new(input);
+        let err = input.lines().flat_map(|l| l.split(',')).map(|n| source.number::<u32>(n)).find_map(Result::err).unwrap();
+        assert_eq!((err.line, err.column), (2, 3));
+        assert_eq!(err.text, "3,x");
+        assert!(err.to_string().ends_with("= note: the input has Windows line endings"));
+    }
+
+    #[test]
+    fn ragged_grid() {
+        let input = "..#\n.#\n...\n";
+        let source = Source::new(input);
+        let err = source.grid(input, |c| c == '.' || c == '#', "`.` or `#`").unwrap_err();
+        assert_eq!((err.line, err.column), (2, 3));
+        assert_eq!(err.found, "end of line");
+    }
+}
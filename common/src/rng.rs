@@ -0,0 +1,137 @@
+use std::ops::RangeInclusive;
+
+use crate::{Direction, Grid, Pos};
+
+/// A small seeded random number generator (SplitMix64) for the input generators. It is
+/// written out here so a seed keeps giving the same input, whatever the dependencies do.
+#[derive(Debug, Clone)]
+pub struct Rng {
+    state: u64,
+}
+
+impl Rng {
+    pub fn new(seed: u64) -> Self {
+        Rng { state: seed }
+    }
+
+    pub fn next_u64(&mut self) -> u64 {
+        self.state = self.state.wrapping_add(0x9e3779b97f4a7c15);
+        let mut z = self.state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58476d1ce4e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d049bb133111eb);
+        z ^ (z >> 31)
+    }
+
+    /// A number below `n`, which must not be 0
+    pub fn below(&mut self, n: usize) -> usize {
+        // The bias of the modulo is too small to matter for inputs
+        (self.next_u64() % n as u64) as usize
+    }
+
+    /// A number in `range`, which must not be empty
+    pub fn range(&mut self, range: RangeInclusive<i64>) -> i64 {
+        let span = range.end().abs_diff(*range.start()).wrapping_add(1);
+        let offset = if span == 0 { self.next_u64() } else { self.next_u64() % span };
+        range.start().wrapping_add_unsigned(offset)
+    }
+
+    /// True with probability `p`
+    pub fn chance(&mut self, p: f64) -> bool {
+        ((self.next_u64() >> 11) as f64 / (1u64 << 53) as f64) < p
+    }
+
+    pub fn pick<'a, T>(&mut self, items: &'a [T]) -> &'a T {
+        &items[self.below(items.len())]
+    }
+
+    pub fn shuffle<T>(&mut self, items: &mut [T]) {
+        for i in (1..items.len()).rev() {
+            items.swap(i, self.below(i + 1));
+        }
+    }
+
+    /// A loop through the positions of a `size` by `size` grid that moves one step at a
+    /// time and never visits a position twice, in walking order. It runs around a blob of
+    /// about half of the squares between the positions.
+    pub fn closed_loop(&mut self, size: usize) -> Vec<Pos> {
+        let size = size.max(2);
+        let mut blob = Grid::new(size - 1, size - 1, false);
+        let first = Pos::new(self.below(size - 1), self.below(size - 1));
+        blob[first] = true;
+        let mut candidates: Vec<Pos> = blob.neighbors4(first).collect();
+        let mut area = 1;
+        while area < (size - 1) * (size - 1) / 2 && !candidates.is_empty() {
+            let square = candidates.swap_remove(self.below(candidates.len()));
+            if !blob[square] && keeps_one_loop(&blob, square) {
+                blob[square] = true;
+                area += 1;
+                candidates.extend(blob.neighbors4(square).filter(|&p| !blob[p]));
+            }
+        }
+
+        // The outline between the blob and the rest, as the directions it leaves each position in
+        let square = |row: usize, col: usize| row > 0 && col > 0 && blob.get(Pos::new(row - 1, col - 1)).is_some_and(|&b| b);
+        let outline = |pos: Pos| -> Vec<Direction> {
+            let (r, c) = (pos.row, pos.col);
+            let (ul, ur, ll, lr) = (square(r, c), square(r, c + 1), square(r + 1, c), square(r + 1, c + 1));
+            [(Direction::Up, ul != ur), (Direction::Down, ll != lr), (Direction::Left, ul != ll), (Direction::Right, ur != lr)]
+                .into_iter()
+                .filter_map(|(direction, crosses)| crosses.then_some(direction))
+                .collect()
+        };
+
+        let positions = Grid::new(size, size, ());
+        let start = positions.positions().find(|&p| !outline(p).is_empty()).unwrap();
+        let mut path = vec![start];
+        let mut direction = outline(start)[0];
+        loop {
+            let pos = positions.step(*path.last().unwrap(), direction).unwrap();
+            if pos == start {
+                return path;
+            }
+            path.push(pos);
+            direction = outline(pos).into_iter().find(|&d| d != direction.opposite()).unwrap();
+        }
+    }
+}
+
+// Whether adding `square` to the blob keeps its outline a single loop: the blob squares
+// around it must be one run that includes a side neighbor, otherwise the blob gets a hole
+// or two squares that only touch at a corner
+fn keeps_one_loop(blob: &Grid<bool>, square: Pos) -> bool {
+    const AROUND: [(isize, isize); 8] = [(-1, 0), (-1, 1), (0, 1), (1, 1), (1, 0), (1, -1), (0, -1), (-1, -1)];
+    let inside: Vec<bool> = AROUND.iter().map(|&offset| blob.offset(square, offset).is_some_and(|p| blob[p])).collect();
+    let runs = (0..8).filter(|&i| inside[i] && !inside[(i + 7) % 8]).count();
+    runs == 1 && (0..8).step_by(2).any(|i| inside[i])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn seeded() {
+        let numbers = |seed| {
+            let mut rng = Rng::new(seed);
+            (0..5).map(|_| rng.range(-3..=3)).collect::<Vec<_>>()
+        };
+        assert_eq!(numbers(7), numbers(7));
+        assert_ne!(numbers(7), numbers(8));
+        assert!(numbers(7).iter().all(|n| (-3..=3).contains(n)));
+    }
+
+    #[test]
+    fn closed_loops() {
+        for seed in 0..20 {
+            let path = Rng::new(seed).closed_loop(12);
+            let mut seen = path.clone();
+            seen.sort();
+            seen.dedup();
+            assert_eq!(seen.len(), path.len());
+            assert!(path.len() >= 4);
+            for (i, &pos) in path.iter().enumerate() {
+                assert_eq!(pos.manhattan(path[(i + 1) % path.len()]), 1);
+            }
+        }
+    }
+}
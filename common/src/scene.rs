@@ -0,0 +1,65 @@
+use crate::{Grid, Pos};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Rgb(pub u8, pub u8, pub u8);
+
+impl Rgb {
+    pub const WHITE: Rgb = Rgb(255, 255, 255);
+    pub const BLACK: Rgb = Rgb(0, 0, 0);
+    pub const RED: Rgb = Rgb(220, 40, 40);
+    pub const GREEN: Rgb = Rgb(60, 170, 60);
+    pub const BLUE: Rgb = Rgb(40, 90, 220);
+
+    /// From black (0) to white (255)
+    pub fn gray(level: u8) -> Rgb {
+        Rgb(level, level, level)
+    }
+}
+
+/// A line through some points, closed and filled for polygons
+#[derive(Debug, Clone, PartialEq)]
+pub struct Shape {
+    pub points: Vec<(f64, f64)>,
+    pub color: Rgb,
+    pub closed: bool,
+    pub fill: Option<Rgb>,
+}
+
+/// A picture of a puzzle state for `aoc run --render`: colored grid cells with lines drawn
+/// on top. Coordinates grow to the right and down, a cell is one unit wide.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Scene {
+    pub width: f64,
+    pub height: f64,
+    pub background: Rgb,
+    pub cells: Option<Grid<Rgb>>,
+    pub shapes: Vec<Shape>,
+}
+
+impl Scene {
+    /// An empty scene, for shapes that are too big for cells
+    pub fn new(width: f64, height: f64) -> Self {
+        Scene { width, height, background: Rgb::WHITE, cells: None, shapes: Vec::new() }
+    }
+
+    pub fn grid(cells: Grid<Rgb>) -> Self {
+        let scene = Scene::new(cells.width() as f64, cells.height() as f64);
+        Scene { cells: Some(cells), ..scene }
+    }
+
+    /// The middle of the cell `pos`, paths through it go there
+    pub fn center(pos: Pos) -> (f64, f64) {
+        (pos.col as f64 + 0.5, pos.row as f64 + 0.5)
+    }
+
+    pub fn path(mut self, points: impl IntoIterator<Item = (f64, f64)>, color: Rgb) -> Self {
+        self.shapes.push(Shape { points: points.into_iter().collect(), color, closed: false, fill: None });
+        self
+    }
+
+    /// Without `fill` only the outline is drawn
+    pub fn polygon(mut self, points: impl IntoIterator<Item = (f64, f64)>, color: Rgb, fill: Option<Rgb>) -> Self {
+        self.shapes.push(Shape { points: points.into_iter().collect(), color, closed: true, fill });
+        self
+    }
+}
@@ -0,0 +1,80 @@
+use crate::{Grid, Pos};
+
+/// How a highlighted cell is shown in the terminal
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Color {
+    Red,
+    Green,
+    Yellow,
+    Blue,
+    Magenta,
+    Cyan,
+}
+
+/// One step of a visualization: a snapshot of a grid with some of its cells highlighted,
+/// and lines of text shown below it
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Frame {
+    pub grid: Option<Grid<char>>,
+    /// Later highlights of the same cell win
+    pub highlights: Vec<(Pos, Color)>,
+    pub text: Vec<String>,
+}
+
+impl Frame {
+    pub fn grid(grid: Grid<char>) -> Self {
+        Frame { grid: Some(grid), ..Frame::default() }
+    }
+
+    /// A frame without a grid
+    pub fn text(line: impl Into<String>) -> Self {
+        Frame::default().line(line)
+    }
+
+    pub fn line(mut self, line: impl Into<String>) -> Self {
+        self.text.push(line.into());
+        self
+    }
+
+    pub fn highlight(mut self, pos: Pos, color: Color) -> Self {
+        self.highlights.push((pos, color));
+        self
+    }
+
+    pub fn highlight_all(mut self, positions: impl IntoIterator<Item = Pos>, color: Color) -> Self {
+        self.highlights.extend(positions.into_iter().map(|pos| (pos, color)));
+        self
+    }
+}
+
+/// The frames a solver pushes while it runs. Solvers share their code between solving and
+/// visualizing, so pushing to a disabled recording does nothing and doesn't build the frame.
+#[derive(Debug, Clone, Default)]
+pub struct Recording {
+    enabled: bool,
+    frames: Vec<Frame>,
+}
+
+impl Recording {
+    pub fn new() -> Self {
+        Recording { enabled: true, frames: Vec::new() }
+    }
+
+    pub fn disabled() -> Self {
+        Recording::default()
+    }
+
+    pub fn is_enabled(&self) -> bool {
+        self.enabled
+    }
+
+    pub fn push(&mut self, frame: impl FnOnce() -> Frame) {
+        if self.enabled {
+            self.frames.push(frame());
+        }
+    }
+
+    pub fn frames(&self) -> &[Frame] {
+        &self.frames
+    }
+}